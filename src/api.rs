@@ -1,4 +1,70 @@
-use reqwest::header::USER_AGENT;
+use crate::config::EdgarConfig;
+use crate::error::EDGARParserError;
+use crate::rate_limit;
+use crate::user_agent::UserAgent;
+use encoding_rs::{Encoding, WINDOWS_1252};
+use flate2::read::GzDecoder;
+use reqwest::header::{
+    ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, HeaderMap, RETRY_AFTER,
+    USER_AGENT,
+};
+use reqwest::{RequestBuilder, StatusCode};
+use std::io::Read;
+use std::time::Duration;
+
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+compile_error!("one of the `rustls-tls` or `native-tls` features must be enabled");
+
+/// Wait before the first retry of `fetch_http_body_with_retry`, doubled for each later one.
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest wait before a retry, whether from backoff or a server's `Retry-After`.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Builds the HTTP client using the TLS backend selected by the crate's cargo features and
+/// the TLS settings in `config`. rustls is preferred when both backends are enabled.
+pub fn build_client(config: &EdgarConfig) -> Result<reqwest::Client, reqwest::Error> {
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(version) = config.min_tls_version {
+        builder = builder.min_tls_version(version);
+    }
+    if let Some(timeout) = config.request_timeout {
+        builder = builder.timeout(timeout);
+    }
+
+    #[cfg(feature = "rustls-tls")]
+    let builder = builder.use_rustls_tls();
+
+    #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+    let builder = builder.use_native_tls();
+
+    builder.build()
+}
+
+/// Resolves the User-Agent for a request: the value configured on `config`, then the
+/// `USER_AGENT` environment variable.
+///
+/// # Errors
+/// Returns `EDGARParserError::MissingUserAgent` if neither is set, or
+/// `EDGARParserError::InvalidUserAgent` if the agent found is not a valid one.
+fn user_agent(config: &EdgarConfig) -> Result<String, EDGARParserError> {
+    resolve_user_agent(
+        config.user_agent.as_deref(),
+        std::env::var("USER_AGENT").ok().as_deref(),
+    )
+}
+
+/// Picks and validates the configured User-Agent, falling back to the environment's.
+fn resolve_user_agent(
+    configured: Option<&str>,
+    env: Option<&str>,
+) -> Result<String, EDGARParserError> {
+    let raw = configured
+        .or(env)
+        .ok_or(EDGARParserError::MissingUserAgent)?;
+    Ok(UserAgent::parse(raw)?.into())
+}
 
 /// Creates and returns a client capable of making requests to the EDGAR system.
 /// Ensure you set the `USER_AGENT` environment variable beforehand.
@@ -8,48 +74,715 @@ use reqwest::header::USER_AGENT;
 /// ```
 /// In Rust projects, it’s recommended to define this in [`/your_project/.cargo/config.toml`](https://doc.rust-lang.org/cargo/reference/config.html#hierarchical-structure).
 ///
+/// Requests time out after 30 seconds; use `fetch_http_body_with_timeout` to change that.
+/// Like every request in this crate, it must run inside a Tokio runtime; otherwise it
+/// fails with `EDGARParserError::NoRuntime`.
+///
 /// Example:
 /// ```
 pub async fn fetch_http_body(url: &str) -> Result<String, Box<dyn std::error::Error>> {
-    // Use custom user agent or fallback
-    let user_agent = std::env::var("USER_AGENT")
-        .unwrap_or_else(|_| "MyRustApp support@myrustapp.com".to_string());
+    fetch_http_body_with_config(url, &EdgarConfig::default()).await
+}
+
+/// Fetches `url` as `fetch_http_body` does, but allows `timeout` for the request instead of
+/// the default 30 seconds. A request that runs over fails with `EDGARParserError::Timeout`.
+pub async fn fetch_http_body_with_timeout(
+    url: &str,
+    timeout: Duration,
+) -> Result<String, Box<dyn std::error::Error>> {
+    fetch_http_body_with_config(url, &EdgarConfig::default().request_timeout(timeout)).await
+}
+
+/// Fetches `url` as `fetch_http_body` does, using the client settings in `config`.
+pub async fn fetch_http_body_with_config(
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let (body, _headers) = fetch_with_headers_with_config(url, config).await?;
+
+    if body.is_empty() {
+        Err("Empty response body".into())
+    } else {
+        Ok(body)
+    }
+}
+
+/// Fetches `url` and returns the response body together with the full response headers,
+/// e.g. to inspect `Last-Modified` or `X-Cache`. Unlike `fetch_http_body`, an empty body is
+/// returned as-is.
+///
+/// # Errors
+/// Returns `EDGARParserError::HttpError` if the request fails or the response status is not
+/// a success.
+pub async fn fetch_with_headers(url: &str) -> Result<(String, HeaderMap), EDGARParserError> {
+    fetch_with_headers_with_config(url, &EdgarConfig::default()).await
+}
+
+/// Fetches `url` as `fetch_with_headers` does, using the client settings in `config`.
+pub async fn fetch_with_headers_with_config(
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<(String, HeaderMap), EDGARParserError> {
+    let (bytes, headers) = fetch_bytes_with_headers(url, config).await?;
+    Ok((decode_response(&bytes, &headers), headers))
+}
+
+/// Sends a GET request for `url` and returns the raw body and headers of a successful response.
+/// The body is requested gzip-compressed and decompressed here; see `decode_content_encoding`.
+pub(crate) async fn fetch_bytes_with_headers(
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<(Vec<u8>, HeaderMap), EDGARParserError> {
+    let request = get_request(url, config)?.header(ACCEPT_ENCODING, "gzip");
+    let response = send_checked(request, url, config).await?;
+    read_body(response, url, config).await
+}
+
+/// Fetches `url` as `fetch_http_body` does, but retries responses the SEC uses to shed load:
+/// `429 Too Many Requests` and the transient `500`, `502`, `503` and `504` statuses. A
+/// successful response with an empty body, which the SEC occasionally serves for a moment,
+/// is retried the same way. Each retry waits for the response's `Retry-After` header when
+/// present, otherwise 1s, 2s, 4s and so on; either wait is capped at one minute. Other
+/// failures are returned immediately.
+///
+/// # Errors
+/// Returns the last failing status as an `EDGARParserError::HttpError`, or
+/// `EDGARParserError::EmptyBody` if the last response was empty, once `max_retries` retries
+/// have been used. Any other request error is returned as `fetch_http_body` does.
+pub async fn fetch_http_body_with_retry(
+    url: &str,
+    max_retries: u32,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let (bytes, headers) =
+        fetch_bytes_with_retry(url, max_retries, &EdgarConfig::default(), RETRY_BASE_DELAY).await?;
+
+    Ok(decode_response(&bytes, &headers))
+}
+
+/// Fetches `url` like `fetch_bytes_with_headers`, retrying retryable statuses and empty
+/// bodies up to `max_retries` times with backoff starting at `base_delay`.
+async fn fetch_bytes_with_retry(
+    url: &str,
+    max_retries: u32,
+    config: &EdgarConfig,
+    base_delay: Duration,
+) -> Result<(Vec<u8>, HeaderMap), EDGARParserError> {
+    let mut attempt = 0;
+
+    loop {
+        let request = get_request(url, config)?.header(ACCEPT_ENCODING, "gzip");
+        let response = send_paced(request, url, config).await?;
+        let status = response.status();
+        let backoff = backoff_delay(base_delay, attempt);
+
+        let delay = if status.is_success() {
+            let (bytes, headers) = read_body(response, url, config).await?;
+            if !bytes.is_empty() {
+                return Ok((bytes, headers));
+            }
+            if attempt == max_retries {
+                return Err(EDGARParserError::EmptyBody(url.to_string()));
+            }
+            log::warn!(
+                "GET {} returned an empty body; retrying in {:?} ({} of {})",
+                url,
+                backoff,
+                attempt + 1,
+                max_retries
+            );
+            backoff
+        } else {
+            if !is_retryable(status) || attempt == max_retries {
+                return Err(status_error(status));
+            }
+            let delay = retry_after(response.headers()).unwrap_or(backoff);
+            log::warn!(
+                "GET {} returned {}; retrying in {:?} ({} of {})",
+                url,
+                status,
+                delay,
+                attempt + 1,
+                max_retries
+            );
+            delay
+        };
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Returns the wait before retry number `attempt + 1`: `base_delay` doubled for every earlier
+/// attempt, capped at `MAX_RETRY_DELAY` so large retry counts neither overflow nor stall.
+fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+    2u32.checked_pow(attempt)
+        .map_or(MAX_RETRY_DELAY, |factor| base_delay.saturating_mul(factor))
+        .min(MAX_RETRY_DELAY)
+}
+
+/// Returns `true` for statuses worth retrying: rate limiting and transient server errors.
+fn is_retryable(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Reads the wait requested by a `Retry-After` header, given either in seconds or as an
+/// HTTP date, capped at `MAX_RETRY_DELAY`. Dates in the past mean no wait.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    let delay = match value.parse::<u64>() {
+        Ok(seconds) => Duration::from_secs(seconds),
+        Err(_) => {
+            let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+            (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                .to_std()
+                .unwrap_or_default()
+        }
+    };
+    Some(delay.min(MAX_RETRY_DELAY))
+}
+
+/// Reads the body of a successful response, decompressing it if needed. The body is read
+/// chunk by chunk so a response larger than the config's `max_response_bytes` is abandoned
+/// as soon as it crosses the limit, or before reading when `Content-Length` already does.
+/// The limit applies again to the decompressed body.
+async fn read_body(
+    mut response: reqwest::Response,
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<(Vec<u8>, HeaderMap), EDGARParserError> {
+    let mut headers = response.headers().clone();
+    let limit = config.max_response_bytes;
+    let too_large = |limit| EDGARParserError::ResponseTooLarge {
+        url: url.to_string(),
+        limit,
+    };
+
+    if let Some(limit) = limit
+        && response.content_length().is_some_and(|len| len > limit)
+    {
+        return Err(too_large(limit));
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| request_error(e, url))? {
+        bytes.extend_from_slice(&chunk);
+        if let Some(limit) = limit
+            && bytes.len() as u64 > limit
+        {
+            return Err(too_large(limit));
+        }
+    }
+    let bytes = decode_content_encoding(bytes, &mut headers, url, limit)?;
+
+    Ok((bytes, headers))
+}
+
+/// Decompresses a body whose `Content-Encoding` is gzip. If the server claims gzip but the
+/// body does not decode, it is logged and treated as identity-encoded rather than failing
+/// the request; SEC mirrors occasionally mislabel plain responses this way. Once decoded,
+/// the `Content-Encoding` and `Content-Length` headers are removed since they no longer
+/// describe the body.
+///
+/// # Errors
+/// Returns `EDGARParserError::ResponseTooLarge` if the decompressed body exceeds `limit`;
+/// decompression stops one byte past it, so a small body cannot expand without bound.
+fn decode_content_encoding(
+    bytes: Vec<u8>,
+    headers: &mut HeaderMap,
+    url: &str,
+    limit: Option<u64>,
+) -> Result<Vec<u8>, EDGARParserError> {
+    let is_gzip = headers
+        .get(CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("gzip"));
+    if !is_gzip {
+        return Ok(bytes);
+    }
+
+    let mut decoded = Vec::new();
+    let read_limit = limit.map_or(u64::MAX, |limit| limit.saturating_add(1));
+    match GzDecoder::new(bytes.as_slice())
+        .take(read_limit)
+        .read_to_end(&mut decoded)
+    {
+        Ok(_) => {
+            if let Some(limit) = limit
+                && decoded.len() as u64 > limit
+            {
+                return Err(EDGARParserError::ResponseTooLarge {
+                    url: url.to_string(),
+                    limit,
+                });
+            }
+            headers.remove(CONTENT_ENCODING);
+            headers.remove(CONTENT_LENGTH);
+            Ok(decoded)
+        }
+        Err(e) => {
+            log::warn!(
+                "Response from {} claimed gzip but failed to decode ({}); using it as-is",
+                url,
+                e
+            );
+            Ok(bytes)
+        }
+    }
+}
 
-    let client = reqwest::Client::new();
+/// Sends a GET request for `url` and returns the response of a successful request without
+/// reading its body, so callers can consume it incrementally.
+///
+/// # Errors
+/// Returns `EDGARParserError::HttpError` if the request fails or the response status is not
+/// a success.
+pub(crate) async fn send_get(
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<reqwest::Response, EDGARParserError> {
+    send_checked(get_request(url, config)?, url, config).await
+}
 
-    let response = client
+/// Builds a GET request for `url` carrying the configured User-Agent.
+fn get_request(url: &str, config: &EdgarConfig) -> Result<RequestBuilder, EDGARParserError> {
+    Ok(build_client(config)
+        .map_err(http_error)?
         .get(url)
-        .header(USER_AGENT, user_agent)
-        .send()
-        .await
-        .unwrap_or_else(|e| {
-            panic!("HTTP request to {} failed: {}", url, e)
-        });
-    // debug the values
-    println!("DEBUG: GET {} response: {:?}", url, response);
-    
+        .header(USER_AGENT, user_agent(config)?))
+}
+
+/// Waits until the process-wide rate limit allows another request and, when `config` sets
+/// a `min_request_interval`, until that long has passed since the previous paced request.
+/// Callers queue on the locks, so concurrent requests are spaced one after another.
+async fn wait_for_request_slot(config: &EdgarConfig) {
+    rate_limit::acquire().await;
+
+    if let Some(interval) = config.min_request_interval {
+        rate_limit::space_request(interval).await;
+    }
+}
+
+/// Checks that a Tokio runtime is running. The HTTP client and the rate limiting delays need
+/// one, and without this check polling a request from another executor panics deep inside
+/// the client with a message that does not mention this crate.
+fn ensure_runtime() -> Result<(), EDGARParserError> {
+    tokio::runtime::Handle::try_current()
+        .map(|_| ())
+        .map_err(|_| EDGARParserError::NoRuntime)
+}
+
+/// Sends `request` once its request slot comes up and returns the response, whatever its status.
+async fn send_paced(
+    request: RequestBuilder,
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<reqwest::Response, EDGARParserError> {
+    ensure_runtime()?;
+    wait_for_request_slot(config).await;
+
+    let response = request.send().await.map_err(|e| request_error(e, url))?;
+    log::debug!("{} response: {:?}", url, response);
+    Ok(response)
+}
+
+/// Sends `request` and returns its response if the status is a success.
+async fn send_checked(
+    request: RequestBuilder,
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<reqwest::Response, EDGARParserError> {
+    let response = send_paced(request, url, config).await?;
+
     // Check if status is success (200..299)
     if !response.status().is_success() {
-        return Err(format!("HTTP request failed: {}", response.status()).into());
+        return Err(status_error(response.status()));
     }
 
-    let body = response.text().await?;
+    Ok(response)
+}
 
-    if body.is_empty() {
-        Err("Empty response body".into())
+/// Reports a non-success response status as an `EDGARParserError::HttpError`.
+fn status_error(status: StatusCode) -> EDGARParserError {
+    EDGARParserError::HttpError(format!("HTTP request failed: {}", status).into())
+}
+
+/// Converts an error from requesting `url`, reporting timeouts as `EDGARParserError::Timeout`.
+fn request_error(e: reqwest::Error, url: &str) -> EDGARParserError {
+    if e.is_timeout() {
+        EDGARParserError::Timeout(url.to_string())
     } else {
-        Ok(body)
+        http_error(e)
+    }
+}
+
+/// Wraps a `reqwest::Error` as an `EDGARParserError::HttpError`.
+pub(crate) fn http_error(e: reqwest::Error) -> EDGARParserError {
+    EDGARParserError::HttpError(Box::new(e))
+}
+
+/// Issues a lightweight HEAD request and returns the response status without downloading a body.
+pub async fn fetch_http_status(
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<reqwest::StatusCode, Box<dyn std::error::Error>> {
+    let request = build_client(config)
+        .map_err(http_error)?
+        .head(url)
+        .header(USER_AGENT, user_agent(config)?);
+    let response = send_paced(request, url, config).await?;
+
+    Ok(response.status())
+}
+
+/// Fetches a filing document and returns its body transcoded to UTF-8.
+/// Older EDGAR documents are often Latin-1 or Windows-1252, so the charset is taken from the
+/// `Content-Type` header when present; otherwise UTF-8 is used if the body is valid UTF-8,
+/// then any `charset=` declared in the document's head, and finally Windows-1252.
+pub async fn fetch_filing_text(url: &str) -> Result<String, Box<dyn std::error::Error>> {
+    fetch_filing_text_with_config(url, &EdgarConfig::default()).await
+}
+
+/// Fetches a filing document as `fetch_filing_text` does, using the client settings in `config`.
+pub async fn fetch_filing_text_with_config(
+    url: &str,
+    config: &EdgarConfig,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let (bytes, headers) = fetch_bytes_with_headers(url, config).await?;
+
+    if bytes.is_empty() {
+        return Err("Empty response body".into());
     }
+
+    Ok(decode_response(&bytes, &headers))
+}
+
+/// Decodes a response body with `decode_body`, taking the charset from its `Content-Type`.
+fn decode_response(bytes: &[u8], headers: &HeaderMap) -> String {
+    let content_type = headers.get(CONTENT_TYPE).and_then(|v| v.to_str().ok());
+    decode_body(bytes, content_type)
+}
+
+/// Decodes a response body to UTF-8 using the charset detection described in `fetch_filing_text`.
+fn decode_body(bytes: &[u8], content_type: Option<&str>) -> String {
+    let encoding = content_type
+        .and_then(charset_label)
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .or_else(|| {
+            std::str::from_utf8(bytes)
+                .is_ok()
+                .then_some(encoding_rs::UTF_8)
+        })
+        .or_else(|| {
+            // Only the document head is searched for a declared charset.
+            let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]);
+            charset_label(&head).and_then(|label| Encoding::for_label(label.as_bytes()))
+        })
+        .unwrap_or(WINDOWS_1252);
+
+    encoding.decode(bytes).0.into_owned()
+}
+
+/// Extracts the value of the first `charset=` parameter in `text`, without quotes.
+fn charset_label(text: &str) -> Option<String> {
+    let lower = text.to_ascii_lowercase();
+    let start = lower.find("charset=")? + "charset=".len();
+    let label: String = lower[start..]
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'))
+        .collect();
+
+    (!label.is_empty()).then_some(label)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "rustls-tls")]
+    #[test]
+    fn test_build_client_with_rustls() {
+        assert!(build_client(&EdgarConfig::for_tests()).is_ok());
+    }
+
+    #[cfg(feature = "native-tls")]
+    #[test]
+    fn test_build_client_with_native_tls() {
+        assert!(build_client(&EdgarConfig::for_tests()).is_ok());
+    }
+
+    #[test]
+    fn test_build_client_with_min_tls_1_2() {
+        let config = EdgarConfig::for_tests().min_tls_version(reqwest::tls::Version::TLS_1_2);
+        assert_eq!(config.min_tls_version, Some(reqwest::tls::Version::TLS_1_2));
+        assert!(build_client(&config).is_ok());
+    }
+
+    // "Café “quoted”" encoded as Windows-1252.
+    const WINDOWS_1252_BODY: &[u8] = b"Caf\xe9 \x93quoted\x94";
+
+    #[tokio::test]
+    async fn test_fetch_filing_text_transcodes_header_charset() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/filing.htm")
+            .with_status(200)
+            .with_header("content-type", "text/html; charset=windows-1252")
+            .with_body(WINDOWS_1252_BODY)
+            .create_async()
+            .await;
+
+        let text = fetch_filing_text_with_config(
+            &format!("{}/filing.htm", server.url()),
+            &EdgarConfig::for_tests(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(text, "Café “quoted”");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_headers_transcodes_header_charset() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/latin.htm")
+            .with_status(200)
+            .with_header("content-type", "text/html; charset=windows-1252")
+            .with_body(WINDOWS_1252_BODY)
+            .create_async()
+            .await;
+
+        let (body, _headers) = fetch_with_headers_with_config(
+            &format!("{}/latin.htm", server.url()),
+            &EdgarConfig::for_tests(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, "Café “quoted”");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_filing_text_falls_back_without_charset() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/filing.txt")
+            .with_status(200)
+            .with_header("content-type", "text/plain")
+            .with_body(WINDOWS_1252_BODY)
+            .create_async()
+            .await;
+
+        let text = fetch_filing_text_with_config(
+            &format!("{}/filing.txt", server.url()),
+            &EdgarConfig::for_tests(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(text, "Café “quoted”");
+    }
+
+    #[test]
+    fn test_resolve_user_agent() {
+        assert_eq!(
+            resolve_user_agent(Some("Adams Incorporated admin@adamsinc.com"), None).unwrap(),
+            "Adams Incorporated admin@adamsinc.com"
+        );
+        assert_eq!(
+            resolve_user_agent(None, Some("Env Agent env@example.com")).unwrap(),
+            "Env Agent env@example.com"
+        );
+        assert_eq!(
+            resolve_user_agent(
+                Some("Config Agent config@example.com"),
+                Some("Env Agent env@example.com")
+            )
+            .unwrap(),
+            "Config Agent config@example.com"
+        );
+    }
+
+    #[test]
+    fn test_resolve_user_agent_rejects_empty_and_missing() {
+        assert!(matches!(
+            resolve_user_agent(Some("  "), None),
+            Err(EDGARParserError::InvalidUserAgent(_))
+        ));
+        assert!(matches!(
+            resolve_user_agent(None, Some("MyRustApp support@myrustapp.com")),
+            Err(EDGARParserError::InvalidUserAgent(_))
+        ));
+        assert!(matches!(
+            resolve_user_agent(None, None),
+            Err(EDGARParserError::MissingUserAgent)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_configured_user_agent_is_sent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/ua")
+            .match_header("user-agent", "Adams Incorporated admin@adamsinc.com")
+            .with_status(200)
+            .with_body("ok")
+            .create_async()
+            .await;
+
+        let config = EdgarConfig::default().with_user_agent(
+            crate::user_agent::UserAgent::new("Adams Incorporated", "admin@adamsinc.com").unwrap(),
+        );
+        let body = fetch_http_body_with_config(&format!("{}/ua", server.url()), &config)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(body, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_builder_user_agent_fails_before_sending() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/ua")
+            .with_status(200)
+            .with_body("ok")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig::default().with_user_agent("no contact address");
+        let result = fetch_with_headers_with_config(&format!("{}/ua", server.url()), &config).await;
+
+        assert!(matches!(result, Err(EDGARParserError::InvalidUserAgent(_))));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_min_request_interval_spaces_requests() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/paced")
+            .with_status(200)
+            .with_body("ok")
+            .create_async()
+            .await;
+
+        // The spacing itself is covered against a fake clock in `rate_limit`; this checks a
+        // config's interval is applied, which holds for the next request a minute later.
+        let config = EdgarConfig::for_tests().min_request_interval(Duration::from_secs(60));
+        fetch_http_body_with_config(&format!("{}/paced", server.url()), &config)
+            .await
+            .unwrap();
+
+        assert!(rate_limit::is_throttling_with_config(&config));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_headers_returns_custom_header() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/headers")
+            .with_status(200)
+            .with_header("x-cache", "HIT")
+            .with_header("last-modified", "Thu, 01 Feb 2024 17:30:01 GMT")
+            .with_body("ok")
+            .create_async()
+            .await;
+
+        let (body, headers) = fetch_with_headers_with_config(
+            &format!("{}/headers", server.url()),
+            &EdgarConfig::for_tests(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(body, "ok");
+        assert_eq!(headers.get("x-cache").unwrap(), "HIT");
+        assert_eq!(
+            headers.get("last-modified").unwrap(),
+            "Thu, 01 Feb 2024 17:30:01 GMT"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_http_body_decodes_gzip() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"compressed body").unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/gzip")
+            .match_header("accept-encoding", "gzip")
+            .with_status(200)
+            .with_header("content-encoding", "gzip")
+            .with_body(gzipped)
+            .create_async()
+            .await;
+
+        let (body, headers) = fetch_with_headers_with_config(
+            &format!("{}/gzip", server.url()),
+            &EdgarConfig::for_tests(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, "compressed body");
+        assert!(headers.get("content-encoding").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_http_body_falls_back_on_corrupt_gzip() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/not-gzip")
+            .with_status(200)
+            .with_header("content-encoding", "gzip")
+            .with_body("plain text after all")
+            .create_async()
+            .await;
+
+        let body = fetch_http_body_with_config(
+            &format!("{}/not-gzip", server.url()),
+            &EdgarConfig::for_tests(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, "plain text after all");
+    }
+
+    #[test]
+    fn test_decode_body_prefers_valid_utf8() {
+        assert_eq!(decode_body("Café".as_bytes(), None), "Café");
+    }
+
+    #[test]
+    fn test_decode_body_uses_meta_charset() {
+        let mut body =
+            b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=iso-8859-1\"><p>"
+                .to_vec();
+        body.extend_from_slice(b"\xa7 1");
+        assert_eq!(
+            decode_body(&body, None),
+            "<meta http-equiv=\"Content-Type\" content=\"text/html; charset=iso-8859-1\"><p>§ 1"
+        );
+    }
+
     #[tokio::test]
     async fn test_get_http_response_body_valid_url() {
         let url = "https://example.com/";
-        let result = fetch_http_body(url).await;
+        let result = fetch_http_body_with_config(url, &EdgarConfig::for_tests()).await;
         assert!(
             result.is_ok(),
             "Expected OK response from example.com, got: {:?}",
@@ -66,7 +799,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_http_response_body_404() {
         let url = "https://example.com/nonexistentpage";
-        let result = fetch_http_body(url).await;
+        let result = fetch_http_body_with_config(url, &EdgarConfig::for_tests()).await;
         assert!(
             result.is_ok(),
             "Expected valid HTTP response even for 404 page"
@@ -81,7 +814,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_http_response_body_invalid_domain() {
         let url = "https://thisdomaindoesnotexist123456789.com/";
-        let result = fetch_http_body(url).await;
+        let result = fetch_http_body_with_config(url, &EdgarConfig::for_tests()).await;
         assert!(
             result.is_err(),
             "Expected error for unreachable domain, got: {:?}",
@@ -89,10 +822,303 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_fetch_http_body_unroutable_host_returns_err() {
+        // Nothing listens on port 1, so the connection is refused without a network round trip.
+        let result =
+            fetch_http_body_with_config("http://127.0.0.1:1/", &EdgarConfig::for_tests()).await;
+        let err = result.expect_err("connection failure should be returned, not panic");
+        assert!(matches!(
+            err.downcast_ref::<EDGARParserError>(),
+            Some(EDGARParserError::HttpError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retry_recovers_after_503s() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("GET", "/busy")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/busy")
+            .with_status(200)
+            .with_body("ready")
+            .expect(1)
+            .create_async()
+            .await;
+        let url = format!("{}/busy", server.url());
+
+        let (body, _headers) = fetch_bytes_with_retry(
+            &url,
+            3,
+            &EdgarConfig::for_tests(),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(body, b"ready");
+        unavailable.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retry_returns_last_status_when_exhausted() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/down")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+        let url = format!("{}/down", server.url());
+
+        let result = fetch_bytes_with_retry(
+            &url,
+            2,
+            &EdgarConfig::for_tests(),
+            Duration::from_millis(10),
+        )
+        .await;
+
+        mock.assert_async().await;
+        assert!(
+            matches!(result, Err(EDGARParserError::HttpError(ref e)) if e.to_string().contains("503"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retry_retries_empty_body() {
+        let mut server = mockito::Server::new_async().await;
+        let empty = server
+            .mock("GET", "/flaky")
+            .with_status(200)
+            .with_body("")
+            .expect(1)
+            .create_async()
+            .await;
+        let full = server
+            .mock("GET", "/flaky")
+            .with_status(200)
+            .with_body("ready")
+            .expect(1)
+            .create_async()
+            .await;
+        let url = format!("{}/flaky", server.url());
+
+        let (body, _headers) = fetch_bytes_with_retry(
+            &url,
+            2,
+            &EdgarConfig::for_tests(),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(body, b"ready");
+        empty.assert_async().await;
+        full.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retry_empty_body_when_exhausted() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/blank")
+            .with_status(200)
+            .with_body("")
+            .expect(2)
+            .create_async()
+            .await;
+        let url = format!("{}/blank", server.url());
+
+        let result = fetch_bytes_with_retry(
+            &url,
+            1,
+            &EdgarConfig::for_tests(),
+            Duration::from_millis(10),
+        )
+        .await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(EDGARParserError::EmptyBody(ref u)) if *u == url));
+    }
+
+    #[tokio::test]
+    async fn test_max_response_bytes_aborts_large_bodies() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/sized")
+            .with_status(200)
+            .with_body("x".repeat(64))
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/chunked")
+            .with_status(200)
+            .with_chunked_body(|w| {
+                for _ in 0..8 {
+                    w.write_all(&[b'x'; 16])?;
+                }
+                Ok(())
+            })
+            .create_async()
+            .await;
+        let config = EdgarConfig::for_tests().max_response_bytes(32);
+
+        for path in ["/sized", "/chunked"] {
+            let url = format!("{}{}", server.url(), path);
+            let result = fetch_bytes_with_headers(&url, &config).await;
+            assert!(
+                matches!(
+                    result,
+                    Err(EDGARParserError::ResponseTooLarge { limit: 32, .. })
+                ),
+                "{} should exceed the limit",
+                path
+            );
+        }
+
+        let url = format!("{}/sized", server.url());
+        let config = EdgarConfig::for_tests().max_response_bytes(64);
+        let (body, _headers) = fetch_bytes_with_headers(&url, &config).await.unwrap();
+        assert_eq!(body.len(), 64);
+    }
+
+    #[tokio::test]
+    async fn test_max_response_bytes_limits_decompressed_body() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&[b'x'; 4096]).unwrap();
+        let gzipped = encoder.finish().unwrap();
+        assert!(gzipped.len() < 256);
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/gzip-bomb")
+            .with_status(200)
+            .with_header("content-encoding", "gzip")
+            .with_body(gzipped)
+            .create_async()
+            .await;
+        let url = format!("{}/gzip-bomb", server.url());
+
+        let config = EdgarConfig::for_tests().max_response_bytes(256);
+        let result = fetch_bytes_with_headers(&url, &config).await;
+        assert!(matches!(
+            result,
+            Err(EDGARParserError::ResponseTooLarge { limit: 256, .. })
+        ));
+
+        let config = EdgarConfig::for_tests().max_response_bytes(4096);
+        let (body, _headers) = fetch_bytes_with_headers(&url, &config).await.unwrap();
+        assert_eq!(body.len(), 4096);
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "2".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, "86400".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(MAX_RETRY_DELAY));
+    }
+
+    #[test]
+    fn test_backoff_delay_is_capped() {
+        let base = Duration::from_secs(1);
+        assert_eq!(backoff_delay(base, 0), base);
+        assert_eq!(backoff_delay(base, 3), Duration::from_secs(8));
+        assert_eq!(backoff_delay(base, 10), MAX_RETRY_DELAY);
+        assert_eq!(backoff_delay(base, 31), MAX_RETRY_DELAY);
+        assert_eq!(backoff_delay(base, 32), MAX_RETRY_DELAY);
+        assert_eq!(backoff_delay(base, u32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_fetch_without_runtime_returns_no_runtime_error() {
+        use std::task::{Context, Poll, Waker};
+
+        // Poll the request by hand, outside any Tokio runtime.
+        let config = EdgarConfig::for_tests();
+        let future = fetch_with_headers_with_config("http://127.0.0.1:1/", &config);
+        let mut future = std::pin::pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(Err(err)) => {
+                assert!(matches!(err, EDGARParserError::NoRuntime));
+                assert!(err.to_string().contains("Tokio runtime"));
+            }
+            other => panic!("expected a NoRuntime error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_http_body_with_timeout() {
+        // Accept connections but never answer, so the request can only end by timing out.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/slow", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                held.push(stream);
+            }
+        });
+
+        let config = EdgarConfig::for_tests().request_timeout(Duration::from_millis(100));
+        let err = fetch_http_body_with_config(&url, &config)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<EDGARParserError>(),
+            Some(EDGARParserError::Timeout(u)) if *u == url
+        ));
+        let message = err.to_string();
+        assert!(message.contains(&url) && message.contains("timed out"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_http_status_with_timeout() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/slow", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                held.push(stream);
+            }
+        });
+
+        let config = EdgarConfig::for_tests().request_timeout(Duration::from_millis(100));
+        let err = fetch_http_status(&url, &config).await.unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<EDGARParserError>(),
+            Some(EDGARParserError::Timeout(u)) if *u == url
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_http_response_body_malformed_url() {
         let url = "not a url";
-        let result = fetch_http_body(url).await;
+        let result = fetch_http_body_with_config(url, &EdgarConfig::for_tests()).await;
         assert!(
             result.is_err(),
             "Expected error for malformed URL input, got: {:?}",
@@ -103,7 +1129,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_http_response_body_with_query_params() {
         let url = "https://httpbin.org/get?name=test&lang=rust";
-        let result = fetch_http_body(url).await;
+        let result = fetch_http_body_with_config(url, &EdgarConfig::for_tests()).await;
         assert!(result.is_ok(), "Expected OK response from httpbin.org");
         let body = result.unwrap();
         assert!(
@@ -0,0 +1,171 @@
+use crate::error::EDGARParserError;
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use serde_xml_rs::from_str as xml_from_str;
+
+/// Represents one filing listed in an EDGAR browse Atom feed.
+///
+/// # Fields
+/// - `title`: Entry title, e.g. `8-K - APPLE INC (0000320193) (Filer)`.
+/// - `form`: The form type from the entry's category, e.g. `8-K`.
+/// - `accession_number`: The accession number parsed from the entry id.
+/// - `filing_href`: Link to the filing's index page in the EDGAR Archives.
+/// - `updated`: Timestamp the entry was last updated, in RFC 3339 format.
+/// - `summary`: The entry summary as published by the SEC (HTML).
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct FilingEntry {
+    pub title: String,
+    pub form: String,
+    pub accession_number: String,
+    pub filing_href: String,
+    pub updated: String,
+    pub summary: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomFeed {
+    #[serde(default)]
+    entry: Vec<AtomEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomEntry {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    link: Option<AtomLink>,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    updated: String,
+    #[serde(default)]
+    category: Option<AtomCategory>,
+    #[serde(default)]
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomLink {
+    #[serde(rename = "@href")]
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomCategory {
+    #[serde(rename = "@term")]
+    term: String,
+}
+
+impl FilingEntry {
+    /// Parses `updated` into a timestamp, so entries published under different UTC offsets
+    /// compare correctly. Returns `None` if it is not valid RFC 3339.
+    pub fn updated_at(&self) -> Option<DateTime<FixedOffset>> {
+        DateTime::parse_from_rfc3339(&self.updated).ok()
+    }
+}
+
+// Prefix of the accession number within an entry id.
+const ACCESSION_ID_MARKER: &str = "accession-number=";
+
+impl From<AtomEntry> for FilingEntry {
+    fn from(entry: AtomEntry) -> Self {
+        let accession_number = entry
+            .id
+            .split_once(ACCESSION_ID_MARKER)
+            .map(|(_, accn)| accn.trim().to_string())
+            .unwrap_or_default();
+
+        FilingEntry {
+            title: entry.title.trim().to_string(),
+            form: entry.category.map(|c| c.term).unwrap_or_default(),
+            accession_number,
+            filing_href: entry.link.map(|l| l.href).unwrap_or_default(),
+            updated: entry.updated.trim().to_string(),
+            summary: entry.summary.trim().to_string(),
+        }
+    }
+}
+
+/// Parses an EDGAR browse Atom feed (`output=atom`) into its filing entries.
+///
+/// # Errors
+/// Returns `EDGARParserError::XMLParseError` if the feed is not valid Atom XML.
+pub fn parse_atom_feed(xml: &str) -> Result<Vec<FilingEntry>, EDGARParserError> {
+    let feed: AtomFeed = xml_from_str(xml)?;
+    Ok(feed.entry.into_iter().map(FilingEntry::from).collect())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const CURRENT_EVENTS_FIXTURE: &str = r#"<?xml version="1.0" encoding="ISO-8859-1" ?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+<title>Latest Filings - Thu, 01 Feb 2024 17:30:01 EST</title>
+<link rel="alternate" href="/cgi-bin/browse-edgar?action=getcurrent"/>
+<link rel="self" href="/cgi-bin/browse-edgar?action=getcurrent"/>
+<id>https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent</id>
+<author><name>Webmaster</name><email>webmaster@sec.gov</email></author>
+<updated>2024-02-01T17:30:01-05:00</updated>
+<entry>
+<title>8-K - APPLE INC (0000320193) (Filer)</title>
+<link rel="alternate" type="text/html" href="https://www.sec.gov/Archives/edgar/data/320193/000032019324000006/0000320193-24-000006-index.htm"/>
+<summary type="html"> &lt;b&gt;Filed:&lt;/b&gt; 2024-02-01 &lt;b&gt;AccNo:&lt;/b&gt; 0000320193-24-000006 &lt;b&gt;Size:&lt;/b&gt; 1 MB</summary>
+<updated>2024-02-01T16:30:41-05:00</updated>
+<category scheme="https://www.sec.gov/" label="form type" term="8-K"/>
+<id>urn:tag:sec.gov,2008:accession-number=0000320193-24-000006</id>
+</entry>
+<entry>
+<title>8-K - MICROSOFT CORP (0000789019) (Filer)</title>
+<link rel="alternate" type="text/html" href="https://www.sec.gov/Archives/edgar/data/789019/000119312524017503/0001193125-24-017503-index.htm"/>
+<summary type="html"> &lt;b&gt;Filed:&lt;/b&gt; 2024-02-01 &lt;b&gt;AccNo:&lt;/b&gt; 0001193125-24-017503 &lt;b&gt;Size:&lt;/b&gt; 245 KB</summary>
+<updated>2024-02-01T16:05:12-05:00</updated>
+<category scheme="https://www.sec.gov/" label="form type" term="8-K"/>
+<id>urn:tag:sec.gov,2008:accession-number=0001193125-24-017503</id>
+</entry>
+</feed>"#;
+
+    #[test]
+    fn test_parse_atom_feed_entries() {
+        let entries = parse_atom_feed(CURRENT_EVENTS_FIXTURE).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].title, "8-K - APPLE INC (0000320193) (Filer)");
+        assert_eq!(entries[0].form, "8-K");
+        assert_eq!(entries[0].accession_number, "0000320193-24-000006");
+        assert_eq!(
+            entries[0].filing_href,
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019324000006/0000320193-24-000006-index.htm"
+        );
+        assert_eq!(entries[1].updated, "2024-02-01T16:05:12-05:00");
+        assert!(entries[1].summary.contains("AccNo:"));
+    }
+
+    #[test]
+    fn test_updated_at_compares_across_offsets() {
+        let eastern = FilingEntry {
+            updated: "2024-02-01T16:30:41-05:00".to_string(),
+            ..Default::default()
+        };
+        let utc = FilingEntry {
+            updated: "2024-02-01T20:00:00+00:00".to_string(),
+            ..Default::default()
+        };
+
+        assert!(eastern.updated_at() > utc.updated_at());
+        assert_eq!(FilingEntry::default().updated_at(), None);
+    }
+
+    #[test]
+    fn test_parse_atom_feed_without_entries() {
+        let xml =
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>Latest Filings</title></feed>"#;
+        assert!(parse_atom_feed(xml).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_atom_feed_invalid_xml() {
+        let result = parse_atom_feed("<feed><entry>");
+        assert!(matches!(result, Err(EDGARParserError::XMLParseError(_))));
+    }
+}
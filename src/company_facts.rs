@@ -0,0 +1,834 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Represents the SEC Company Facts XBRL JSON for a single company.
+///
+/// # Fields
+/// - `cik`: The company's Central Index Key (CIK) number.
+/// - `entity_name`: The company's registered name.
+/// - `facts`: Concepts keyed by taxonomy (e.g. `us-gaap`, `dei`) and then by tag (e.g. `Assets`).
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyFacts {
+    pub cik: u32,
+    pub entity_name: String,
+    #[serde(default)]
+    pub facts: HashMap<String, HashMap<String, Concept>>,
+}
+
+/// Represents a single XBRL concept and its reported values, grouped by unit of measure.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct Concept {
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub units: HashMap<String, Vec<FactPoint>>,
+}
+
+/// Represents one reported value of a concept.
+///
+/// # Fields
+/// - `start`: Start of the reporting period, absent for instantaneous facts.
+/// - `end`: End of the reporting period (or the instant), formatted `YYYY-MM-DD`.
+/// - `val`: The reported value.
+/// - `accn`: Accession number of the filing that reported the value.
+/// - `fy`: Fiscal year of the filing.
+/// - `fp`: Fiscal period of the filing (`FY`, `Q1`, `Q2`, `Q3`).
+/// - `form`: Form type of the filing (e.g. `10-K`).
+/// - `filed`: Date the filing was made, formatted `YYYY-MM-DD`.
+/// - `frame`: Calendrical frame the value was assigned to, if any (e.g. `CY2023Q4I`).
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct FactPoint {
+    #[serde(default)]
+    pub start: Option<String>,
+    pub end: String,
+    pub val: f64,
+    pub accn: String,
+    #[serde(default)]
+    pub fy: Option<i32>,
+    #[serde(default)]
+    pub fp: Option<String>,
+    pub form: String,
+    pub filed: String,
+    #[serde(default)]
+    pub frame: Option<String>,
+}
+
+/// The length of the period a fact point covers, classified from its `start` and `end` with
+/// the tolerances of the frames API: a year is 365 days +/- 30 days, a quarter 91 days +/- 30
+/// days.
+///
+/// # Variants
+/// - `Instant`: A point-in-time value without a `start`, e.g. a balance.
+/// - `Quarterly`: A value covering about three months.
+/// - `Annual`: A value covering about a year.
+/// - `Other`: Any other duration, e.g. six- or nine-month year-to-date values.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PeriodLength {
+    Instant,
+    Quarterly,
+    Annual,
+    Other,
+}
+
+impl FactPoint {
+    /// Classifies the period the value covers. Returns `None` when `start` or `end` is not a
+    /// `YYYY-MM-DD` date.
+    pub fn period_length(&self) -> Option<PeriodLength> {
+        let parse = |date: &str| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok();
+        let end = parse(&self.end)?;
+        let Some(start) = &self.start else {
+            return Some(PeriodLength::Instant);
+        };
+
+        let length = match (end - parse(start)?).num_days() {
+            335..=395 => PeriodLength::Annual,
+            61..=121 => PeriodLength::Quarterly,
+            _ => PeriodLength::Other,
+        };
+        Some(length)
+    }
+}
+
+/// A flat row describing one reported value of a concept, ready to be written to CSV or
+/// loaded into a data frame.
+#[derive(Debug, Default, Serialize, PartialEq, Clone)]
+pub struct ConceptRecord {
+    pub end: String,
+    pub val: f64,
+    pub fy: Option<i32>,
+    pub fp: Option<String>,
+    pub form: String,
+    pub filed: String,
+    pub accn: String,
+}
+
+/// A historical value that a later filing reported differently.
+///
+/// # Fields
+/// - `period_end`: End of the restated period, formatted `YYYY-MM-DD`.
+/// - `old_value`: The value as previously reported.
+/// - `new_value`: The value as reported by the later filing.
+/// - `old_accn`: Accession number of the filing that reported `old_value`.
+/// - `new_accn`: Accession number of the filing that reported `new_value`.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Restatement {
+    pub period_end: String,
+    pub old_value: f64,
+    pub new_value: f64,
+    pub old_accn: String,
+    pub new_accn: String,
+}
+
+/// Headline figures computed from a company's facts. Revenue and net income are for the
+/// latest fiscal year, balances as of the latest period end; each is `None` when the company
+/// does not report the underlying tag.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FinancialSummary {
+    pub revenue: Option<f64>,
+    pub net_income: Option<f64>,
+    pub total_assets: Option<f64>,
+    pub total_liabilities: Option<f64>,
+    pub shares_outstanding: Option<f64>,
+}
+
+// Revenue tags in order of preference; companies moved to ASC 606 tagging from 2018.
+const REVENUE_TAGS: [&str; 3] = [
+    "Revenues",
+    "RevenueFromContractWithCustomerExcludingAssessedTax",
+    "SalesRevenueNet",
+];
+
+impl Concept {
+    /// Returns the value with the latest period end for `unit`, preferring the most recent
+    /// filing when several report the same period.
+    pub fn latest(&self, unit: &str) -> Option<&FactPoint> {
+        self.units
+            .get(unit)?
+            .iter()
+            .max_by(|a, b| (&a.end, &a.filed).cmp(&(&b.end, &b.filed)))
+    }
+
+    /// Returns the value with the earliest period end for `unit`, preferring the first filing
+    /// when several report the same period.
+    pub fn earliest(&self, unit: &str) -> Option<&FactPoint> {
+        self.units
+            .get(unit)?
+            .iter()
+            .min_by(|a, b| (&a.end, &a.filed).cmp(&(&b.end, &b.filed)))
+    }
+
+    /// Returns the annual values reported for `uom`, i.e. those with a fiscal period of `FY`.
+    pub fn annual(&self, uom: &str) -> Vec<FactPoint> {
+        self.points_where(uom, |p| p.fp.as_deref() == Some("FY"))
+    }
+
+    /// Returns the quarterly values reported for `uom`, i.e. every value not reported as `FY`.
+    pub fn quarterly(&self, uom: &str) -> Vec<FactPoint> {
+        self.points_where(uom, |p| p.fp.as_deref() != Some("FY"))
+    }
+
+    /// Computes the year-over-year percentage change of the annual series for `uom`, i.e. the
+    /// values covering about a year (`PeriodLength::Annual`). Annual reports also carry
+    /// fourth-quarter values tagged `FY`, so the fiscal period is not used. Each year is the
+    /// calendar year of the period end; when several filings report the
+    /// same year (comparatives, amendments) the most recently filed value is used. A year is
+    /// only compared with the one immediately before it, so gaps are skipped rather than
+    /// interpolated, as are years following a zero value.
+    ///
+    /// Returns `(year, percent)` pairs in ascending year order, e.g. `(2023, 12.5)`.
+    pub fn yoy_growth(&self, uom: &str) -> Vec<(u16, f64)> {
+        let mut by_year: BTreeMap<u16, &FactPoint> = BTreeMap::new();
+        let points = self.units.get(uom).into_iter().flatten();
+
+        for point in points.filter(|p| p.period_length() == Some(PeriodLength::Annual)) {
+            let Some(year) = point.end.get(..4).and_then(|y| y.parse::<u16>().ok()) else {
+                continue;
+            };
+            let current = by_year.entry(year).or_insert(point);
+            if point.filed > current.filed {
+                *current = point;
+            }
+        }
+
+        by_year
+            .iter()
+            .zip(by_year.iter().skip(1))
+            .filter(|((prev_year, prev), (year, _))| **year == **prev_year + 1 && prev.val != 0.0)
+            .map(|((_, prev), (year, point))| {
+                (*year, (point.val - prev.val) / prev.val.abs() * 100.0)
+            })
+            .collect()
+    }
+
+    /// Finds values for `uom` that a later filing reported differently for the same period.
+    /// Periods are matched on both `start` and `end`, so a quarter and the fiscal year ending
+    /// on the same day are never compared. Each change between consecutive filings of a period
+    /// is one restatement; re-reporting an unchanged value is not.
+    ///
+    /// Returns restatements ordered by period end, then by filing date.
+    pub fn restatements(&self, uom: &str) -> Vec<Restatement> {
+        let mut points: Vec<&FactPoint> = self.units.get(uom).into_iter().flatten().collect();
+        points.sort_by(|a, b| (&a.end, &a.filed).cmp(&(&b.end, &b.filed)));
+
+        let mut previous: HashMap<(Option<&str>, &str), &FactPoint> = HashMap::new();
+        let mut restatements = Vec::new();
+
+        for point in points {
+            let period = (point.start.as_deref(), point.end.as_str());
+            if let Some(old) = previous.insert(period, point)
+                && old.val != point.val
+            {
+                restatements.push(Restatement {
+                    period_end: point.end.clone(),
+                    old_value: old.val,
+                    new_value: point.val,
+                    old_accn: old.accn.clone(),
+                    new_accn: point.accn.clone(),
+                });
+            }
+        }
+
+        restatements
+    }
+
+    /// Flattens the values reported for `uom` into `ConceptRecord` rows, in their original order.
+    pub fn to_records(&self, uom: &str) -> Vec<ConceptRecord> {
+        self.units
+            .get(uom)
+            .map(|points| {
+                points
+                    .iter()
+                    .map(|p| ConceptRecord {
+                        end: p.end.clone(),
+                        val: p.val,
+                        fy: p.fy,
+                        fp: p.fp.clone(),
+                        form: p.form.clone(),
+                        filed: p.filed.clone(),
+                        accn: p.accn.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the time series for `uom`, one value per period ordered by period end (then
+    /// start), e.g. for charting a line item. A period reported by several filings, such as
+    /// comparatives and amendments, keeps only its most recently filed value.
+    pub fn series(&self, uom: &str) -> Vec<FactPoint> {
+        let mut series = self
+            .units
+            .get(uom)
+            .map(|points| latest_per_period(points))
+            .unwrap_or_default();
+        series.sort_by(|a, b| (&a.end, &a.start).cmp(&(&b.end, &b.start)));
+        series
+    }
+
+    /// Returns the reconciled values of `series` covering periods of `length`, keyed by
+    /// period end, e.g. for charting annual values without the quarters a concept also
+    /// reports. Points with unparseable dates are skipped.
+    pub fn series_map(&self, uom: &str, length: PeriodLength) -> BTreeMap<NaiveDate, f64> {
+        self.series(uom)
+            .into_iter()
+            .filter(|point| point.period_length() == Some(length))
+            .filter_map(|point| {
+                let end = NaiveDate::parse_from_str(&point.end, "%Y-%m-%d").ok()?;
+                Some((end, point.val))
+            })
+            .collect()
+    }
+
+    /// Returns a copy of the concept keeping only the latest-filed value per unit and period.
+    fn reconciled(&self) -> Concept {
+        let units = self
+            .units
+            .iter()
+            .map(|(unit, points)| (unit.clone(), latest_per_period(points)))
+            .collect();
+
+        Concept {
+            label: self.label.clone(),
+            description: self.description.clone(),
+            units,
+        }
+    }
+
+    /// Clones the values reported for `uom` that satisfy `predicate`, in their original order.
+    fn points_where<P>(&self, uom: &str, predicate: P) -> Vec<FactPoint>
+    where
+        P: Fn(&FactPoint) -> bool,
+    {
+        self.units
+            .get(uom)
+            .map(|points| points.iter().filter(|p| predicate(p)).cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl CompanyFacts {
+    /// Looks up a concept by taxonomy and tag, e.g. `("us-gaap", "Assets")`.
+    pub fn concept(&self, taxonomy: &str, tag: &str) -> Option<&Concept> {
+        self.facts.get(taxonomy)?.get(tag)
+    }
+
+    /// Returns the taxonomies the company reports under, sorted, e.g. `["dei", "us-gaap"]`
+    /// for a domestic filer or `["dei", "ifrs-full"]` for a foreign one.
+    pub fn taxonomies(&self) -> Vec<String> {
+        let mut taxonomies: Vec<String> = self.facts.keys().cloned().collect();
+        taxonomies.sort();
+        taxonomies
+    }
+
+    /// Returns a copy of the facts with amendments reconciled: for every concept, unit and
+    /// period (`start`, `end`), only the most recently `filed` value is kept. Periods keep
+    /// the position of their first appearance.
+    pub fn reconciled(&self) -> CompanyFacts {
+        let facts = self
+            .facts
+            .iter()
+            .map(|(taxonomy, concepts)| {
+                let concepts = concepts
+                    .iter()
+                    .map(|(tag, concept)| (tag.clone(), concept.reconciled()))
+                    .collect();
+                (taxonomy.clone(), concepts)
+            })
+            .collect();
+
+        CompanyFacts {
+            cik: self.cik,
+            entity_name: self.entity_name.clone(),
+            facts,
+        }
+    }
+
+    /// Lists the fact points reported now that were not in `previous`, e.g. an earlier pull of
+    /// the same company. A point is identified by its concept, unit, accession number and
+    /// period, so a value re-reported by a new filing counts as new. Concepts are named
+    /// `taxonomy:tag` (e.g. `us-gaap:Assets`) and returned in name order, each concept's
+    /// points in their original order.
+    pub fn new_facts_since(&self, previous: &CompanyFacts) -> Vec<(String, FactPoint)> {
+        let mut known = HashSet::new();
+        for (taxonomy, concepts) in &previous.facts {
+            for (tag, concept) in concepts {
+                for (unit, points) in &concept.units {
+                    for point in points {
+                        known.insert(fact_key(taxonomy, tag, unit, point));
+                    }
+                }
+            }
+        }
+
+        let mut new_facts = Vec::new();
+        let mut names: Vec<(&String, &String)> = self
+            .facts
+            .iter()
+            .flat_map(|(taxonomy, concepts)| concepts.keys().map(move |tag| (taxonomy, tag)))
+            .collect();
+        names.sort();
+
+        for (taxonomy, tag) in names {
+            let concept = &self.facts[taxonomy][tag];
+            let mut units: Vec<&String> = concept.units.keys().collect();
+            units.sort();
+
+            for unit in units {
+                for point in &concept.units[unit] {
+                    if !known.contains(&fact_key(taxonomy, tag, unit, point)) {
+                        new_facts.push((format!("{}:{}", taxonomy, tag), point.clone()));
+                    }
+                }
+            }
+        }
+
+        new_facts
+    }
+
+    /// Computes the latest revenue, net income, total assets, total liabilities and shares
+    /// outstanding from the standard `us-gaap` and `dei` tags. Revenue and net income only
+    /// consider annual values, so a later quarter never stands in for the year. Companies
+    /// change revenue tags over time, so revenue comes from whichever tag reports the latest
+    /// year, preferring the order of `REVENUE_TAGS` on ties.
+    pub fn summary(&self) -> FinancialSummary {
+        let latest = |taxonomy: &str, tag: &str, unit: &str| {
+            self.concept(taxonomy, tag)
+                .and_then(|c| c.latest(unit))
+                .map(|p| p.val)
+        };
+        let latest_annual = |tag: &str| {
+            self.concept("us-gaap", tag)?
+                .units
+                .get("USD")?
+                .iter()
+                .filter(|p| p.period_length() == Some(PeriodLength::Annual))
+                .max_by(|a, b| (&a.end, &a.filed).cmp(&(&b.end, &b.filed)))
+        };
+
+        FinancialSummary {
+            revenue: REVENUE_TAGS
+                .iter()
+                .filter_map(|tag| latest_annual(tag))
+                .reduce(|best, point| if point.end > best.end { point } else { best })
+                .map(|p| p.val),
+            net_income: latest_annual("NetIncomeLoss").map(|p| p.val),
+            total_assets: latest("us-gaap", "Assets", "USD"),
+            total_liabilities: latest("us-gaap", "Liabilities", "USD"),
+            shares_outstanding: latest("dei", "EntityCommonStockSharesOutstanding", "shares"),
+        }
+    }
+}
+
+/// Keeps the most recently filed point for every period (`start`, `end`). Periods keep the
+/// position of their first appearance.
+fn latest_per_period(points: &[FactPoint]) -> Vec<FactPoint> {
+    let mut reconciled: Vec<FactPoint> = Vec::with_capacity(points.len());
+    let mut positions: HashMap<(Option<&str>, &str), usize> = HashMap::new();
+
+    for point in points {
+        let period = (point.start.as_deref(), point.end.as_str());
+        match positions.get(&period) {
+            Some(&i) if reconciled[i].filed > point.filed => {}
+            Some(&i) => reconciled[i] = point.clone(),
+            None => {
+                positions.insert(period, reconciled.len());
+                reconciled.push(point.clone());
+            }
+        }
+    }
+
+    reconciled
+}
+
+/// Identifies a fact point within a company's facts: concept, unit, filing and period.
+fn fact_key<'a>(
+    taxonomy: &'a str,
+    tag: &'a str,
+    unit: &'a str,
+    point: &'a FactPoint,
+) -> (&'a str, &'a str, &'a str, &'a str, Option<&'a str>, &'a str) {
+    (
+        taxonomy,
+        tag,
+        unit,
+        point.accn.as_str(),
+        point.start.as_deref(),
+        point.end.as_str(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPANY_FACTS_FIXTURE: &str = r#"
+        {
+            "cik": 320193,
+            "entityName": "Apple Inc.",
+            "facts": {
+                "dei": {
+                    "EntityCommonStockSharesOutstanding": {
+                        "label": "Entity Common Stock, Shares Outstanding",
+                        "units": {
+                            "shares": [
+                                {"end": "2022-10-14", "val": 15908118000, "accn": "0000320193-22-000108", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2022-10-28", "frame": "CY2022Q3I"},
+                                {"end": "2023-10-20", "val": 15552752000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03", "frame": "CY2023Q3I"}
+                            ]
+                        }
+                    }
+                },
+                "us-gaap": {
+                    "Assets": {
+                        "label": "Assets",
+                        "units": {
+                            "USD": [
+                                {"end": "2023-09-30", "val": 352583000000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"},
+                                {"end": "2022-09-24", "val": 352755000000, "accn": "0000320193-22-000108", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2022-10-28"}
+                            ]
+                        }
+                    },
+                    "Liabilities": {
+                        "label": "Liabilities",
+                        "units": {
+                            "USD": [
+                                {"end": "2023-09-30", "val": 290437000000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                            ]
+                        }
+                    },
+                    "RevenueFromContractWithCustomerExcludingAssessedTax": {
+                        "label": "Revenue from Contract with Customer, Excluding Assessed Tax",
+                        "units": {
+                            "USD": [
+                                {"start": "2021-09-26", "end": "2022-09-24", "val": 394328000000, "accn": "0000320193-22-000108", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2022-10-28"},
+                                {"start": "2022-09-25", "end": "2023-09-30", "val": 383285000000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                            ]
+                        }
+                    }
+                }
+            }
+        }
+    "#;
+
+    #[test]
+    fn test_deserialize_company_facts() {
+        let facts: CompanyFacts = serde_json::from_str(COMPANY_FACTS_FIXTURE).unwrap();
+        assert_eq!(facts.cik, 320193);
+        assert_eq!(facts.entity_name, "Apple Inc.");
+        assert!(facts.concept("us-gaap", "Assets").is_some());
+        assert!(facts.concept("us-gaap", "Goodwill").is_none());
+    }
+
+    #[test]
+    fn test_summary_values() {
+        let facts: CompanyFacts = serde_json::from_str(COMPANY_FACTS_FIXTURE).unwrap();
+        let summary = facts.summary();
+
+        assert_eq!(
+            summary,
+            FinancialSummary {
+                revenue: Some(383285000000.0),
+                net_income: None,
+                total_assets: Some(352583000000.0),
+                total_liabilities: Some(290437000000.0),
+                shares_outstanding: Some(15552752000.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_uses_latest_annual_values() {
+        let facts: CompanyFacts = serde_json::from_str(
+            r#"{
+                "cik": 1,
+                "entityName": "Example Corp",
+                "facts": {
+                    "us-gaap": {
+                        "Revenues": {
+                            "units": {
+                                "USD": [
+                                    {"start": "2017-01-01", "end": "2017-12-31", "val": 500, "accn": "0000000001-18-000001", "fy": 2017, "fp": "FY", "form": "10-K", "filed": "2018-02-15"}
+                                ]
+                            }
+                        },
+                        "RevenueFromContractWithCustomerExcludingAssessedTax": {
+                            "units": {
+                                "USD": [
+                                    {"start": "2023-01-01", "end": "2023-12-31", "val": 900, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                                    {"start": "2024-01-01", "end": "2024-03-31", "val": 250, "accn": "0000000001-24-000005", "fy": 2024, "fp": "Q1", "form": "10-Q", "filed": "2024-05-01"}
+                                ]
+                            }
+                        },
+                        "NetIncomeLoss": {
+                            "units": {
+                                "USD": [
+                                    {"start": "2023-01-01", "end": "2023-12-31", "val": 80, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                                    {"start": "2024-01-01", "end": "2024-03-31", "val": 15, "accn": "0000000001-24-000005", "fy": 2024, "fp": "Q1", "form": "10-Q", "filed": "2024-05-01"}
+                                ]
+                            }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let summary = facts.summary();
+        assert_eq!(summary.revenue, Some(900.0));
+        assert_eq!(summary.net_income, Some(80.0));
+    }
+
+    #[test]
+    fn test_annual_and_quarterly_split() {
+        let json = r#"
+            {
+                "label": "Net Income (Loss)",
+                "units": {
+                    "USD": [
+                        {"start": "2022-09-25", "end": "2022-12-31", "val": 29998000000, "accn": "0000320193-23-000006", "fy": 2023, "fp": "Q1", "form": "10-Q", "filed": "2023-02-03"},
+                        {"start": "2022-12-25", "end": "2023-04-01", "val": 24160000000, "accn": "0000320193-23-000064", "fy": 2023, "fp": "Q2", "form": "10-Q", "filed": "2023-05-05"},
+                        {"start": "2022-09-25", "end": "2023-09-30", "val": 96995000000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"},
+                        {"start": "2023-09-26", "end": "2023-12-30", "val": 33916000000, "accn": "0000320193-24-000006", "fy": 2024, "fp": "Q1", "form": "10-Q", "filed": "2024-02-02"}
+                    ]
+                }
+            }
+        "#;
+        let concept: Concept = serde_json::from_str(json).unwrap();
+
+        let annual = concept.annual("USD");
+        assert_eq!(annual.len(), 1);
+        assert!(annual.iter().all(|p| p.fp.as_deref() == Some("FY")));
+        assert_eq!(annual[0].val, 96995000000.0);
+
+        let quarterly = concept.quarterly("USD");
+        assert_eq!(quarterly.len(), 3);
+        assert!(quarterly.iter().all(|p| p.fp.as_deref() != Some("FY")));
+
+        assert!(concept.annual("shares").is_empty());
+    }
+
+    #[test]
+    fn test_reconciled_keeps_latest_filed_amendment() {
+        let json = r#"
+            {
+                "cik": 320193,
+                "entityName": "Apple Inc.",
+                "facts": {
+                    "us-gaap": {
+                        "NetIncomeLoss": {
+                            "units": {
+                                "USD": [
+                                    {"start": "2022-01-01", "end": "2022-12-31", "val": 100, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                                    {"start": "2023-01-01", "end": "2023-12-31", "val": 150, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                                    {"start": "2022-01-01", "end": "2022-12-31", "val": 90, "accn": "0000000001-23-000007", "fy": 2022, "fp": "FY", "form": "10-K/A", "filed": "2023-06-30"},
+                                    {"start": "2022-01-01", "end": "2022-12-31", "val": 100, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-01-10"}
+                                ]
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+        let facts: CompanyFacts = serde_json::from_str(json).unwrap();
+        let reconciled = facts.reconciled();
+        let points = &reconciled
+            .concept("us-gaap", "NetIncomeLoss")
+            .unwrap()
+            .units["USD"];
+
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].end, "2022-12-31");
+        assert_eq!(points[0].val, 90.0);
+        assert_eq!(points[0].form, "10-K/A");
+        assert_eq!(points[1].val, 150.0);
+    }
+
+    #[test]
+    fn test_series_map() {
+        let concept: Concept = serde_json::from_str(
+            r#"{
+                "units": {
+                    "USD": [
+                        {"start": "2023-01-01", "end": "2023-12-31", "val": 150, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                        {"start": "2022-01-01", "end": "2022-12-31", "val": 100, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                        {"start": "2022-01-01", "end": "2022-12-31", "val": 90, "accn": "0000000001-23-000007", "fy": 2022, "fp": "FY", "form": "10-K/A", "filed": "2023-06-30"}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let series = concept.series_map("USD", PeriodLength::Annual);
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            series.into_iter().collect::<Vec<_>>(),
+            vec![(date("2022-12-31"), 90.0), (date("2023-12-31"), 150.0)]
+        );
+        assert!(concept.series_map("EUR", PeriodLength::Annual).is_empty());
+    }
+
+    #[test]
+    fn test_series_map_separates_period_lengths() {
+        let concept: Concept = serde_json::from_str(
+            r#"{
+                "units": {
+                    "USD": [
+                        {"start": "2022-01-01", "end": "2022-12-31", "val": 100, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                        {"start": "2023-01-01", "end": "2023-03-31", "val": 30, "accn": "0000000001-23-000005", "fy": 2023, "fp": "Q1", "form": "10-Q", "filed": "2023-05-01"},
+                        {"start": "2023-04-01", "end": "2023-06-30", "val": 35, "accn": "0000000001-23-000009", "fy": 2023, "fp": "Q2", "form": "10-Q", "filed": "2023-08-01"},
+                        {"start": "2023-01-01", "end": "2023-06-30", "val": 65, "accn": "0000000001-23-000009", "fy": 2023, "fp": "Q2", "form": "10-Q", "filed": "2023-08-01"},
+                        {"start": "2023-10-01", "end": "2023-12-31", "val": 40, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                        {"start": "2023-01-01", "end": "2023-12-31", "val": 150, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        assert_eq!(
+            concept.series_map("USD", PeriodLength::Annual),
+            BTreeMap::from([(date("2022-12-31"), 100.0), (date("2023-12-31"), 150.0)])
+        );
+        assert_eq!(
+            concept.series_map("USD", PeriodLength::Quarterly),
+            BTreeMap::from([
+                (date("2023-03-31"), 30.0),
+                (date("2023-06-30"), 35.0),
+                (date("2023-12-31"), 40.0),
+            ])
+        );
+        assert_eq!(
+            concept.series_map("USD", PeriodLength::Other),
+            BTreeMap::from([(date("2023-06-30"), 65.0)])
+        );
+        assert!(concept.series_map("USD", PeriodLength::Instant).is_empty());
+    }
+
+    #[test]
+    fn test_new_facts_since() {
+        let previous: CompanyFacts = serde_json::from_str(COMPANY_FACTS_FIXTURE).unwrap();
+        let mut current = previous.clone();
+        let new_point: FactPoint = serde_json::from_str(
+            r#"{"end": "2023-12-30", "val": 279414000000, "accn": "0000320193-24-000006", "fy": 2024, "fp": "Q1", "form": "10-Q", "filed": "2024-02-02"}"#,
+        )
+        .unwrap();
+        current
+            .facts
+            .get_mut("us-gaap")
+            .unwrap()
+            .get_mut("Liabilities")
+            .unwrap()
+            .units
+            .get_mut("USD")
+            .unwrap()
+            .push(new_point.clone());
+
+        assert_eq!(
+            current.new_facts_since(&previous),
+            vec![("us-gaap:Liabilities".to_string(), new_point)]
+        );
+        assert!(previous.new_facts_since(&previous).is_empty());
+    }
+
+    #[test]
+    fn test_to_records_serializes_rows() {
+        let facts: CompanyFacts = serde_json::from_str(COMPANY_FACTS_FIXTURE).unwrap();
+        let concept = facts.concept("us-gaap", "Assets").unwrap();
+
+        let records = concept.to_records("USD");
+        assert_eq!(records.len(), 2);
+
+        let json = serde_json::to_value(&records).unwrap();
+        let rows = json.as_array().unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["end"], "2023-09-30");
+        assert_eq!(rows[0]["fp"], "FY");
+        assert_eq!(rows[0]["accn"], "0000320193-23-000106");
+
+        assert!(concept.to_records("EUR").is_empty());
+    }
+
+    #[test]
+    fn test_taxonomies() {
+        let facts: CompanyFacts = serde_json::from_str(COMPANY_FACTS_FIXTURE).unwrap();
+        assert_eq!(facts.taxonomies(), ["dei", "us-gaap"]);
+        assert!(CompanyFacts::default().taxonomies().is_empty());
+    }
+
+    #[test]
+    fn test_earliest_point() {
+        let facts: CompanyFacts = serde_json::from_str(COMPANY_FACTS_FIXTURE).unwrap();
+        let concept = facts.concept("us-gaap", "Assets").unwrap();
+
+        let earliest = concept.earliest("USD").unwrap();
+        assert_eq!(earliest.end, "2022-09-24");
+        assert_eq!(earliest.val, 352755000000.0);
+        assert!(concept.earliest("EUR").is_none());
+    }
+
+    #[test]
+    fn test_yoy_growth() {
+        let json = r#"
+            {
+                "label": "Revenues",
+                "units": {
+                    "USD": [
+                        {"start": "2021-01-01", "end": "2021-12-31", "val": 100, "accn": "0000000001-22-000001", "fy": 2021, "fp": "FY", "form": "10-K", "filed": "2022-02-15"},
+                        {"start": "2022-01-01", "end": "2022-12-31", "val": 120, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                        {"start": "2023-01-01", "end": "2023-03-31", "val": 40, "accn": "0000000001-23-000005", "fy": 2023, "fp": "Q1", "form": "10-Q", "filed": "2023-05-01"},
+                        {"start": "2023-01-01", "end": "2023-12-31", "val": 90, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                        {"start": "2023-10-01", "end": "2023-12-31", "val": 20, "accn": "0000000001-24-000007", "fy": 2023, "fp": "FY", "form": "10-K/A", "filed": "2024-03-01"},
+                        {"start": "2021-01-01", "end": "2021-12-31", "val": 100, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                        {"start": "2025-01-01", "end": "2025-12-31", "val": 200, "accn": "0000000001-26-000001", "fy": 2025, "fp": "FY", "form": "10-K", "filed": "2026-02-15"}
+                    ]
+                }
+            }
+        "#;
+        let concept: Concept = serde_json::from_str(json).unwrap();
+
+        // The fourth quarter tagged FY is ignored; 2024 is missing, so 2025 has no growth value.
+        assert_eq!(concept.yoy_growth("USD"), vec![(2022, 20.0), (2023, -25.0)]);
+        assert!(concept.yoy_growth("EUR").is_empty());
+    }
+
+    #[test]
+    fn test_restatements() {
+        let json = r#"
+            {
+                "label": "Net Income (Loss)",
+                "units": {
+                    "USD": [
+                        {"start": "2022-01-01", "end": "2022-12-31", "val": 100, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                        {"start": "2022-10-01", "end": "2022-12-31", "val": 30, "accn": "0000000001-23-000001", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-15"},
+                        {"start": "2023-01-01", "end": "2023-12-31", "val": 150, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                        {"start": "2022-01-01", "end": "2022-12-31", "val": 90, "accn": "0000000001-24-000001", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-15"},
+                        {"start": "2023-01-01", "end": "2023-12-31", "val": 150, "accn": "0000000001-25-000001", "fy": 2024, "fp": "FY", "form": "10-K", "filed": "2025-02-15"}
+                    ]
+                }
+            }
+        "#;
+        let concept: Concept = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            concept.restatements("USD"),
+            vec![Restatement {
+                period_end: "2022-12-31".to_string(),
+                old_value: 100.0,
+                new_value: 90.0,
+                old_accn: "0000000001-23-000001".to_string(),
+                new_accn: "0000000001-24-000001".to_string(),
+            }]
+        );
+        assert!(concept.restatements("EUR").is_empty());
+    }
+
+    #[test]
+    fn test_summary_empty_facts() {
+        let summary = CompanyFacts::default().summary();
+        assert_eq!(summary, FinancialSummary::default());
+    }
+}
@@ -0,0 +1,410 @@
+use crate::error::EDGARParserError;
+use crate::user_agent::UserAgent;
+use reqwest::tls::Version;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use url::Url;
+
+/// URL of the SEC company ticker file used to resolve tickers to CIKs.
+pub const COMPANY_TICKERS_URL: &str = "https://www.sec.gov/files/company_tickers.json";
+
+/// URL of the SEC ticker file that also lists each ticker's exchange, in columnar form.
+pub const COMPANY_TICKERS_EXCHANGE_URL: &str =
+    "https://www.sec.gov/files/company_tickers_exchange.json";
+
+/// Base URL of the SEC data APIs (submissions, company facts, frames).
+pub const DATA_BASE_URL: &str = "https://data.sec.gov";
+
+/// Base URL of the EDGAR filing archives.
+pub const ARCHIVES_BASE_URL: &str = "https://www.sec.gov/Archives/edgar/data";
+
+/// Base URL of the EDGAR quarterly full indexes (`master.idx`, `form.idx`, ...).
+pub const FULL_INDEX_BASE_URL: &str = "https://www.sec.gov/Archives/edgar/full-index";
+
+/// URL of the EDGAR browse interface serving the company and current-events Atom feeds.
+pub const BROWSE_EDGAR_URL: &str = "https://www.sec.gov/cgi-bin/browse-edgar";
+
+/// Base URL of the EDGAR full-text search (EFTS) API.
+pub const EFTS_BASE_URL: &str = "https://efts.sec.gov";
+
+/// Version segment of the EFTS API path; the SEC currently only serves `LATEST`.
+pub const EFTS_DEFAULT_VERSION: &str = "LATEST";
+
+/// Width of the zero-padded CIK expected by every SEC endpoint.
+pub const SEC_CIK_WIDTH: usize = 10;
+
+/// Time allowed for a request, from connecting until the body is read, unless configured.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `EdgarConfig` holds client-wide settings shared by the `EdgarParser` lookups.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgarConfig {
+    // Primary location of the company ticker file.
+    pub ticker_url: String,
+
+    // Mirrors of the ticker file tried in order when the primary request fails.
+    pub ticker_fallback_urls: Vec<String>,
+
+    // Location of the columnar ticker file that includes each ticker's exchange.
+    pub ticker_exchange_url: String,
+
+    // Width used when presenting a zero-padded CIK to the caller. SEC endpoints always use `SEC_CIK_WIDTH`.
+    pub cik_pad_width: usize,
+
+    // Base URL of the SEC data APIs.
+    pub data_base_url: String,
+
+    // Base URL filing documents are downloaded from.
+    pub archives_base_url: String,
+
+    // Base URL of the quarterly full indexes.
+    pub full_index_base_url: String,
+
+    // URL of the EDGAR browse interface (`browse-edgar`).
+    pub browse_edgar_url: String,
+
+    // Version segment of the EFTS full-text search path, e.g. `LATEST`.
+    pub efts_version: String,
+
+    // Keep the ticker file in memory after the first download so later ticker lookups in the
+    // process reuse it, shared by every config with the same ticker URL. Off by default, so
+    // each lookup downloads the file.
+    pub cache_ticker_file: bool,
+
+    // Include a truncated snippet of the response body in JSON parse errors. Off by default
+    // so large payloads are not copied into error messages.
+    pub capture_body_on_error: bool,
+
+    // User-Agent sent with every request. Falls back to the `USER_AGENT` environment variable.
+    pub user_agent: Option<String>,
+
+    // Oldest TLS version the HTTP client will negotiate. `None` keeps the backend's default.
+    pub min_tls_version: Option<reqwest::tls::Version>,
+
+    // Fixed minimum spacing between consecutive requests, on top of any per-call rate
+    // limiting. `None` sends requests as soon as they are issued.
+    pub min_request_interval: Option<Duration>,
+
+    // Time allowed for each request, from connecting until the body is read. `None` waits
+    // indefinitely.
+    pub request_timeout: Option<Duration>,
+
+    // Largest response body, in bytes both as sent over the wire and after decompression,
+    // that a request will download before aborting. `None` downloads bodies of any size.
+    pub max_response_bytes: Option<u64>,
+}
+
+impl Default for EdgarConfig {
+    fn default() -> Self {
+        Self {
+            ticker_url: COMPANY_TICKERS_URL.to_string(),
+            ticker_fallback_urls: Vec::new(),
+            ticker_exchange_url: COMPANY_TICKERS_EXCHANGE_URL.to_string(),
+            cik_pad_width: SEC_CIK_WIDTH,
+            data_base_url: DATA_BASE_URL.to_string(),
+            archives_base_url: ARCHIVES_BASE_URL.to_string(),
+            full_index_base_url: FULL_INDEX_BASE_URL.to_string(),
+            browse_edgar_url: BROWSE_EDGAR_URL.to_string(),
+            efts_version: EFTS_DEFAULT_VERSION.to_string(),
+            cache_ticker_file: false,
+            capture_body_on_error: false,
+            user_agent: None,
+            min_tls_version: None,
+            min_request_interval: None,
+            request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
+            max_response_bytes: None,
+        }
+    }
+}
+
+impl EdgarConfig {
+    /// Sets the User-Agent sent with every request, from a raw string or a validated `UserAgent`.
+    /// A raw string is checked when a request is made: if it is not a valid agent, the
+    /// request fails with `EDGARParserError::InvalidUserAgent` before anything is sent. Use
+    /// `set_user_agent` to check it up front instead.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the User-Agent sent with every request after checking it is a valid one.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::InvalidUserAgent` if `user_agent` is blank, has no `@`
+    /// contact address, or is a placeholder. The current agent is left unchanged.
+    pub fn set_user_agent(&mut self, user_agent: &str) -> Result<(), EDGARParserError> {
+        self.user_agent = Some(UserAgent::parse(user_agent)?.into());
+        Ok(())
+    }
+
+    /// Sets the oldest TLS version the HTTP client will negotiate, e.g. `Version::TLS_1_2`.
+    pub fn min_tls_version(mut self, version: reqwest::tls::Version) -> Self {
+        self.min_tls_version = Some(version);
+        self
+    }
+
+    /// Sets a minimum delay between consecutive requests, e.g. `Duration::from_millis(500)`.
+    /// The spacing is enforced across every request issued with such a config, so requests
+    /// made concurrently are queued rather than sent in a burst.
+    pub fn min_request_interval(mut self, interval: Duration) -> Self {
+        self.min_request_interval = Some(interval);
+        self
+    }
+
+    /// Sets the time allowed for each request, replacing the 30 second default.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Caps the size of every response body at `bytes`, both as downloaded and after gzip
+    /// decompression. Larger downloads are aborted with `EDGARParserError::ResponseTooLarge`.
+    pub fn max_response_bytes(mut self, bytes: u64) -> Self {
+        self.max_response_bytes = Some(bytes);
+        self
+    }
+
+    /// Returns the default config with a User-Agent, so tests never depend on the environment.
+    #[cfg(test)]
+    pub(crate) fn for_tests() -> Self {
+        Self::default().with_user_agent("agora_sec_edgar tests dev@example.com")
+    }
+
+    /// Returns the primary ticker URL followed by each fallback mirror, in the order they are tried.
+    pub fn ticker_urls(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.ticker_url.as_str())
+            .chain(self.ticker_fallback_urls.iter().map(String::as_str))
+    }
+
+    /// Builds the EFTS full-text search URL for `query`, using the configured API version.
+    pub fn efts_search_url(&self, query: &str) -> Result<Url, EDGARParserError> {
+        let mut url = Url::parse(&format!(
+            "{}/{}/search-index",
+            EFTS_BASE_URL, self.efts_version
+        ))?;
+        url.query_pairs_mut().append_pair("q", query);
+        Ok(url)
+    }
+}
+
+/// Client settings in a serializable form, for loading an `EdgarConfig` from a TOML or JSON
+/// file. Every field is optional; anything left out keeps the `EdgarConfig` default.
+///
+/// # Fields
+/// - `user_agent`: User-Agent sent with every request.
+/// - `min_request_interval_ms`: Minimum spacing between requests, in milliseconds.
+/// - `min_tls_version`: Oldest TLS version to negotiate: `"1.0"`, `"1.1"`, `"1.2"` or `"1.3"`.
+/// - `ticker_url`: Primary location of the company ticker file.
+/// - `ticker_fallback_urls`: Mirrors of the ticker file tried in order.
+/// - `data_base_url`: Base URL of the SEC data APIs.
+/// - `archives_base_url`: Base URL filing documents are downloaded from.
+/// - `capture_body_on_error`: Include a snippet of the body in JSON parse errors.
+/// - `max_response_bytes`: Largest response body to download, in bytes.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientConfig {
+    pub user_agent: Option<String>,
+    pub min_request_interval_ms: Option<u64>,
+    pub min_tls_version: Option<String>,
+    pub ticker_url: Option<String>,
+    pub ticker_fallback_urls: Option<Vec<String>>,
+    pub data_base_url: Option<String>,
+    pub archives_base_url: Option<String>,
+    pub capture_body_on_error: Option<bool>,
+    pub max_response_bytes: Option<u64>,
+}
+
+impl EdgarConfig {
+    /// Builds a config from deserialized `ClientConfig` settings, starting from the defaults.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::InvalidConfig` if `min_tls_version` is not a known version, or
+    /// `EDGARParserError::InvalidUserAgent` if `user_agent` lacks a valid contact email, so a
+    /// misconfigured agent is caught before the SEC answers it with 403.
+    pub fn from_client_config(settings: ClientConfig) -> Result<Self, EDGARParserError> {
+        let mut config = EdgarConfig::default();
+
+        if let Some(version) = settings.min_tls_version {
+            config.min_tls_version = Some(match version.as_str() {
+                "1.0" => Version::TLS_1_0,
+                "1.1" => Version::TLS_1_1,
+                "1.2" => Version::TLS_1_2,
+                "1.3" => Version::TLS_1_3,
+                _ => {
+                    return Err(EDGARParserError::InvalidConfig(format!(
+                        "unknown TLS version {}",
+                        version
+                    )));
+                }
+            });
+        }
+
+        config.user_agent = settings
+            .user_agent
+            .map(|user_agent| UserAgent::parse(&user_agent).map(String::from))
+            .transpose()?;
+        config.min_request_interval = settings.min_request_interval_ms.map(Duration::from_millis);
+        config.ticker_url = settings.ticker_url.unwrap_or(config.ticker_url);
+        config.ticker_fallback_urls = settings
+            .ticker_fallback_urls
+            .unwrap_or(config.ticker_fallback_urls);
+        config.data_base_url = settings.data_base_url.unwrap_or(config.data_base_url);
+        config.archives_base_url = settings
+            .archives_base_url
+            .unwrap_or(config.archives_base_url);
+        config.capture_body_on_error = settings
+            .capture_body_on_error
+            .unwrap_or(config.capture_body_on_error);
+        config.max_response_bytes = settings.max_response_bytes;
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_agent::UserAgent;
+
+    #[test]
+    fn test_default_config() {
+        let config = EdgarConfig::default();
+        assert_eq!(config.ticker_url, COMPANY_TICKERS_URL);
+        assert!(config.ticker_fallback_urls.is_empty());
+        assert_eq!(config.cik_pad_width, SEC_CIK_WIDTH);
+        assert_eq!(config.efts_version, EFTS_DEFAULT_VERSION);
+        assert!(!config.capture_body_on_error);
+    }
+
+    #[test]
+    fn test_efts_search_url_uses_version() {
+        let default_url = EdgarConfig::default()
+            .efts_search_url("net income")
+            .unwrap();
+        assert_eq!(
+            default_url.as_str(),
+            "https://efts.sec.gov/LATEST/search-index?q=net+income"
+        );
+
+        let config = EdgarConfig {
+            efts_version: "2026-01".to_string(),
+            ..Default::default()
+        };
+        let url = config.efts_search_url("net income").unwrap();
+        assert_eq!(url.path(), "/2026-01/search-index");
+    }
+
+    #[test]
+    fn test_with_user_agent() {
+        let raw = EdgarConfig::default().with_user_agent("Raw Agent raw@example.com");
+        assert_eq!(raw.user_agent.as_deref(), Some("Raw Agent raw@example.com"));
+
+        let typed = EdgarConfig::default()
+            .with_user_agent(UserAgent::new("Typed Agent", "typed@example.com").unwrap());
+        assert_eq!(
+            typed.user_agent.as_deref(),
+            Some("Typed Agent typed@example.com")
+        );
+    }
+
+    #[test]
+    fn test_from_client_config_toml() {
+        let settings: ClientConfig = toml::from_str(
+            r#"
+            user_agent = "Adams Incorporated admin@adamsinc.com"
+            min_request_interval_ms = 250
+            min_tls_version = "1.2"
+            ticker_fallback_urls = ["https://mirror.example.com/company_tickers.json"]
+            "#,
+        )
+        .unwrap();
+        let config = EdgarConfig::from_client_config(settings).unwrap();
+
+        assert_eq!(
+            config.user_agent.as_deref(),
+            Some("Adams Incorporated admin@adamsinc.com")
+        );
+        assert_eq!(
+            config.min_request_interval,
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(config.min_tls_version, Some(Version::TLS_1_2));
+        assert_eq!(config.ticker_fallback_urls.len(), 1);
+        assert_eq!(config.ticker_url, COMPANY_TICKERS_URL);
+        assert_eq!(config.data_base_url, DATA_BASE_URL);
+        assert!(crate::api::build_client(&config).is_ok());
+
+        let empty = EdgarConfig::from_client_config(ClientConfig::default()).unwrap();
+        assert_eq!(empty, EdgarConfig::default());
+    }
+
+    #[test]
+    fn test_from_client_config_rejects_unknown_tls_version() {
+        let settings: ClientConfig = toml::from_str(r#"min_tls_version = "2.0""#).unwrap();
+        assert!(matches!(
+            EdgarConfig::from_client_config(settings),
+            Err(EDGARParserError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_client_config_validates_user_agent() {
+        let settings: ClientConfig =
+            toml::from_str(r#"user_agent = "Adams Incorporated  admin@adamsinc.com ""#).unwrap();
+        let config = EdgarConfig::from_client_config(settings).unwrap();
+        assert_eq!(
+            config.user_agent.as_deref(),
+            Some("Adams Incorporated admin@adamsinc.com")
+        );
+
+        let settings: ClientConfig =
+            toml::from_str(r#"user_agent = "Adams Incorporated admin""#).unwrap();
+        assert!(matches!(
+            EdgarConfig::from_client_config(settings),
+            Err(EDGARParserError::InvalidUserAgent(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_user_agent_validates() {
+        let mut config = EdgarConfig::default();
+        config
+            .set_user_agent("Adams Incorporated admin@adamsinc.com")
+            .unwrap();
+        assert_eq!(
+            config.user_agent.as_deref(),
+            Some("Adams Incorporated admin@adamsinc.com")
+        );
+
+        for invalid in ["", "Adams Incorporated", "MyRustApp support@myrustapp.com"] {
+            assert!(matches!(
+                config.set_user_agent(invalid),
+                Err(EDGARParserError::InvalidUserAgent(_))
+            ));
+        }
+        assert_eq!(
+            config.user_agent.as_deref(),
+            Some("Adams Incorporated admin@adamsinc.com")
+        );
+    }
+
+    #[test]
+    fn test_ticker_urls_order() {
+        let config = EdgarConfig {
+            ticker_fallback_urls: vec![
+                "https://mirror-a.example.com/company_tickers.json".to_string(),
+                "https://mirror-b.example.com/company_tickers.json".to_string(),
+            ],
+            ..Default::default()
+        };
+        let urls: Vec<&str> = config.ticker_urls().collect();
+        assert_eq!(
+            urls,
+            vec![
+                COMPANY_TICKERS_URL,
+                "https://mirror-a.example.com/company_tickers.json",
+                "https://mirror-b.example.com/company_tickers.json",
+            ]
+        );
+    }
+}
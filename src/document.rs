@@ -0,0 +1,157 @@
+// Tags whose content is never visible text.
+const HIDDEN_TAGS: [&str; 3] = ["script", "style", "head"];
+
+// Tags that start a new line of text when rendered.
+const BLOCK_TAGS: [&str; 16] = [
+    "br", "p", "div", "tr", "li", "table", "h1", "h2", "h3", "h4", "h5", "h6", "title", "hr", "ul",
+    "ol",
+];
+
+/// Strips the markup from an HTML filing document and returns its visible text.
+/// Script, style and head content is dropped, block-level elements become line breaks,
+/// common character entities are decoded and runs of whitespace are collapsed.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len() / 2);
+    let mut rest = html;
+
+    while let Some(open) = rest.find('<') {
+        text.push_str(&rest[..open]);
+        rest = &rest[open..];
+
+        let Some(close) = rest.find('>') else {
+            // Drop an unterminated trailing tag.
+            rest = "";
+            break;
+        };
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+
+        let name = tag_name(tag);
+        if !tag.starts_with('/') && HIDDEN_TAGS.contains(&name.as_str()) {
+            // Skip everything up to the matching closing tag.
+            let closing = format!("</{}", name);
+            rest = match rest.to_ascii_lowercase().find(&closing) {
+                Some(end) => &rest[end..],
+                None => "",
+            };
+        } else if BLOCK_TAGS.contains(&name.as_str()) {
+            text.push('\n');
+        } else {
+            text.push(' ');
+        }
+    }
+    text.push_str(rest);
+
+    collapse_whitespace(&decode_entities(&text))
+}
+
+/// Returns the lowercase element name of a tag's inner text, e.g. `div` for `/DIV class="x"`.
+fn tag_name(tag: &str) -> String {
+    tag.trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Decodes named entities common in EDGAR documents and all numeric character references.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Decodes a single entity body (the text between `&` and `;`).
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "nbsp" => Some(' '),
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "rsquo" | "lsquo" => Some('\''),
+        "rdquo" | "ldquo" => Some('"'),
+        "mdash" => Some('—'),
+        "ndash" => Some('–'),
+        _ => {
+            let number = entity.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            // Non-breaking spaces are rendered as plain spaces.
+            if code == 160 {
+                Some(' ')
+            } else {
+                char::from_u32(code)
+            }
+        }
+    }
+}
+
+/// Collapses whitespace within each line and drops empty lines.
+fn collapse_whitespace(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text_strips_markup() {
+        let html = r#"
+            <html>
+                <head><title>aapl-20230930</title><style>p { color: red; }</style></head>
+                <body>
+                    <div><span style="font-weight:bold">PART&#160;I</span></div>
+                    <p>Item 1.&nbsp;&nbsp;Business</p>
+                    <script type="text/javascript">var x = "<p>hidden</p>";</script>
+                    <p>Apple Inc. designs &amp; sells smartphones&#8212;and more.</p>
+                </body>
+            </html>
+        "#;
+
+        assert_eq!(
+            html_to_text(html),
+            "PART I\nItem 1. Business\nApple Inc. designs & sells smartphones—and more."
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_plain_text() {
+        assert_eq!(html_to_text("  no   markup here "), "no markup here");
+    }
+
+    #[test]
+    fn test_decode_entities_leaves_unknown() {
+        assert_eq!(decode_entities("AT&T &bogus; &#x41;"), "AT&T &bogus; A");
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+
+/// Controls where `EdgarParser::download_filing` writes a filing's documents beneath the
+/// chosen output directory.
+///
+/// # Variants
+/// - `Flat`: Every document directly in the output directory, e.g. `aapl-20230930.htm`.
+/// - `NestedByCik`: One folder per company, e.g. `320193/aapl-20230930.htm`.
+/// - `NestedByAccession`: One folder per filing within its company's folder, mirroring the
+///   EDGAR Archives, e.g. `320193/0000320193-23-000106/aapl-20230930.htm`. This layout never
+///   mixes documents of different filings, so it is the default.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum DownloadLayout {
+    Flat,
+    NestedByCik,
+    #[default]
+    NestedByAccession,
+}
+
+impl DownloadLayout {
+    /// Returns the path `document` of the filing `accession` by company `cik` is written to.
+    pub fn path_for(&self, dir: &Path, cik: u32, accession: &str, document: &str) -> PathBuf {
+        match self {
+            DownloadLayout::Flat => dir.join(document),
+            DownloadLayout::NestedByCik => dir.join(cik.to_string()).join(document),
+            DownloadLayout::NestedByAccession => {
+                dir.join(cik.to_string()).join(accession).join(document)
+            }
+        }
+    }
+}
+
+/// The outcome of a bulk download such as `EdgarParser::download_facts_index`.
+///
+/// # Fields
+/// - `downloaded`: CIKs whose file was fetched and written by this run.
+/// - `skipped`: CIKs whose file already existed, so no request was made.
+/// - `failed`: CIKs that could not be downloaded, with the reason.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DownloadReport {
+    pub downloaded: Vec<u32>,
+    pub skipped: Vec<u32>,
+    pub failed: Vec<(u32, String)>,
+}
+
+impl DownloadReport {
+    /// Returns `true` if no CIK failed.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
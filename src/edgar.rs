@@ -1,7 +1,29 @@
-use crate::api::fetch_http_body;
+use crate::api::{
+    fetch_bytes_with_headers, fetch_filing_text_with_config, fetch_http_body_with_config,
+    fetch_http_status,
+};
+use crate::atom::{FilingEntry, parse_atom_feed};
+use crate::company_facts::{CompanyFacts, Concept, FactPoint};
+use crate::config::{BROWSE_EDGAR_URL, EdgarConfig, SEC_CIK_WIDTH};
+use crate::document::html_to_text;
+use crate::download::{DownloadLayout, DownloadReport};
 use crate::error::EDGARParserError;
-use serde::Deserialize;
-use std::collections::HashMap;
+use crate::filing_index::FilingIndex;
+use crate::filing_type_builder::FilingTypeOption;
+use crate::frames::{FrameDatum, FrameHeader, Period, Taxonomy, frames_url_at, stream_frames};
+use crate::full_index::{IndexEntry, stream_master_index};
+use crate::ownership::{Transaction, parse_ownership_document, raw_ownership_document};
+use crate::submissions::{Filing, RecentFilings, Submissions};
+use crate::ticker_index::TickerIndex;
+use crate::watchlist::WatchlistReport;
+use chrono::NaiveDate;
+use futures_util::Stream;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use url::Url;
 
 /// Represents a company record with CIK, ticker, title, and a zero-padded CIK string.
 ///
@@ -10,6 +32,7 @@ use std::collections::HashMap;
 /// - `ticker`: The stock ticker symbol.
 /// - `title`: Company name/title.
 /// - `leading_zero_cik`: Zero-padded string version of `cik_str`, exactly 10 digits.
+/// - `config`: Client settings the parser was created with.
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct EdgarParser {
     pub cik_str: Option<u32>,
@@ -20,6 +43,9 @@ pub struct EdgarParser {
 
     #[serde(deserialize_with = "pad_cik")]
     pub leading_zero_cik: String,
+
+    #[serde(skip)]
+    pub config: EdgarConfig,
 }
 
 /// Custom deserializer to convert a `u32` CIK into a zero-padded 10-digit string.
@@ -27,18 +53,131 @@ fn pad_cik<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    let cik: u32 = Deserialize::deserialize(deserializer)?;
-    Ok(format!("{:010}", cik))
+    let cik = cik_number(deserializer)?;
+    Ok(format!("{:0width$}", cik, width = SEC_CIK_WIDTH))
+}
+
+/// A CIK as it appears in SEC JSON: usually a number, but quoted (and possibly zero-padded)
+/// in some files and mirrors.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CikValue {
+    Number(u32),
+    Text(String),
+}
+
+/// Custom deserializer accepting a CIK given either as a JSON number or a numeric string,
+/// e.g. `320193` or `"0000320193"`.
+fn cik_number<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match CikValue::deserialize(deserializer)? {
+        CikValue::Number(cik) => Ok(cik),
+        CikValue::Text(text) => text
+            .trim()
+            .parse()
+            .map_err(|_| serde::de::Error::custom(format!("invalid CIK {:?}", text))),
+    }
+}
+
+/// Ownership forms that report insider transactions, as listed in the submissions JSON.
+const INSIDER_TRANSACTION_FORMS: [&str; 4] = ["4", "4/A", "5", "5/A"];
+
+/// Maximum number of characters of a response body captured in a parse error.
+const BODY_SNIPPET_LEN: usize = 200;
+
+/// Parses a JSON response body, attaching a truncated snippet of the body to the error
+/// when `capture_body_on_error` is enabled in `config`.
+pub(crate) fn parse_json<T: DeserializeOwned>(
+    body: &str,
+    config: &EdgarConfig,
+) -> Result<T, EDGARParserError> {
+    serde_json::from_str(body).map_err(|source| {
+        if config.capture_body_on_error {
+            EDGARParserError::JSONParseErrorWithBody {
+                source,
+                body_snippet: body.chars().take(BODY_SNIPPET_LEN).collect(),
+            }
+        } else {
+            EDGARParserError::JSONParseError(source)
+        }
+    })
 }
 
 /// Represents raw company data from the SEC without a padded CIK.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct CompanyData {
+    #[serde(deserialize_with = "cik_number")]
     pub cik_str: u32,
     pub ticker: String,
     pub title: String,
 }
 
+/// Represents the columnar `company_tickers_exchange.json` file, where `fields` names the
+/// columns of every row in `data`.
+#[derive(Debug, Deserialize)]
+struct TickerExchangeFile {
+    fields: Vec<String>,
+    data: Vec<Vec<serde_json::Value>>,
+}
+
+impl TickerExchangeFile {
+    /// Returns the exchange listed for `ticker`, or `None` if the ticker is absent or has no
+    /// exchange. Tickers are matched case-insensitively.
+    fn exchange_for(&self, ticker: &str) -> Result<Option<String>, EDGARParserError> {
+        let column = |name: &str| {
+            self.fields.iter().position(|f| f == name).ok_or_else(|| {
+                EDGARParserError::UnexpectedSchema(format!(
+                    "ticker exchange file has no `{}` field",
+                    name
+                ))
+            })
+        };
+        let ticker_column = column("ticker")?;
+        let exchange_column = column("exchange")?;
+
+        let exchange = self
+            .data
+            .iter()
+            .find(|row| {
+                row.get(ticker_column)
+                    .and_then(|t| t.as_str())
+                    .is_some_and(|t| t.eq_ignore_ascii_case(ticker))
+            })
+            .and_then(|row| row.get(exchange_column))
+            .and_then(|e| e.as_str())
+            .map(str::to_string);
+
+        Ok(exchange)
+    }
+}
+
+/// Formats a data API URL for `path` under an alternate base URL.
+fn data_sec_url_at(data_base_url: &str, path: &str) -> String {
+    format!("{}/{}", data_base_url.trim_end_matches('/'), path)
+}
+
+/// Formats the Company Facts endpoint for `cik` under the data API base URL.
+fn company_facts_url_at(data_base_url: &str, cik: u32) -> String {
+    data_sec_url_at(
+        data_base_url,
+        &format!(
+            "api/xbrl/companyfacts/CIK{:0width$}.json",
+            cik,
+            width = SEC_CIK_WIDTH
+        ),
+    )
+}
+
+/// Formats the Company Submissions endpoint for `cik` under the data API base URL.
+pub(crate) fn submissions_url_at(data_base_url: &str, cik: u32) -> String {
+    data_sec_url_at(
+        data_base_url,
+        &format!("submissions/CIK{:0width$}.json", cik, width = SEC_CIK_WIDTH),
+    )
+}
+
 impl EdgarParser {
     /// Creates a new `EdgarParser` by querying the SEC company list for a given ticker symbol.
     ///
@@ -54,27 +193,339 @@ impl EdgarParser {
 
     /// Internal helper to create an `EdgarParser` by searching the ticker list.
     pub async fn create_from_ticker(ticker: &str) -> Result<EdgarParser, EDGARParserError> {
-        let json_body = fetch_http_body("https://www.sec.gov/files/company_tickers.json")
+        Self::create_from_ticker_with_config(ticker, &EdgarConfig::default()).await
+    }
+
+    /// Creates an `EdgarParser` by searching the ticker list, using the ticker URLs from `config`.
+    /// The primary ticker URL is tried first, followed by each fallback mirror in order. The
+    /// ticker list is downloaded for each lookup unless `config` enables `cache_ticker_file`;
+    /// see `refresh_ticker_cache`.
+    ///
+    /// # Errors
+    /// Returns the `EDGARParserError::HttpError` of the last URL tried if every request fails,
+    /// `EDGARParserError::JSONParseError`, or `EDGARParserError::NotFound`.
+    pub async fn create_from_ticker_with_config(
+        ticker: &str,
+        config: &EdgarConfig,
+    ) -> Result<EdgarParser, EDGARParserError> {
+        let index = TickerIndex::cached(config).await?;
+        Self::create_from_ticker_index(ticker, &index, config)
+    }
+
+    /// Downloads the SEC ticker file again, replacing the copy that ticker lookups such as
+    /// `create_from_ticker` and `search_by_name` reuse for the rest of the process when
+    /// `cache_ticker_file` is enabled.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` if every request fails or
+    /// `EDGARParserError::JSONParseError` if the body cannot be parsed. The previous copy is
+    /// kept in that case.
+    pub async fn refresh_ticker_cache() -> Result<(), EDGARParserError> {
+        Self::refresh_ticker_cache_with_config(&EdgarConfig::default()).await
+    }
+
+    /// Refreshes the cached ticker file as `refresh_ticker_cache` does, using the ticker URLs
+    /// in `config`.
+    pub async fn refresh_ticker_cache_with_config(
+        config: &EdgarConfig,
+    ) -> Result<(), EDGARParserError> {
+        TickerIndex::refresh_cached(config).await?;
+        Ok(())
+    }
+
+    /// Resolves a ticker to every company known to have held it: the current holder from the
+    /// ticker file first, followed by each of `candidate_ciks` whose submissions show they
+    /// held the ticker, either by still listing it or by filings named after it (see
+    /// `Submissions::name_while_holding`). The SEC publishes no ticker history, so callers
+    /// supply the CIKs to check, e.g. from their own records.
+    ///
+    /// Returns `(cik, company name)` pairs, naming former holders as they were called while
+    /// they held the ticker; the candidates' submissions are fetched one at a time.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the ticker is not in the ticker file, or
+    /// `EDGARParserError::HttpError` / `EDGARParserError::JSONParseError` if a request fails.
+    pub async fn resolve_ticker_with_history(
+        ticker: &str,
+        candidate_ciks: &[u32],
+    ) -> Result<Vec<(u32, String)>, EDGARParserError> {
+        Self::resolve_ticker_with_history_with_config(
+            ticker,
+            candidate_ciks,
+            &EdgarConfig::default(),
+        )
+        .await
+    }
+
+    /// Resolves a ticker's holders as `resolve_ticker_with_history` does, using the URLs in `config`.
+    pub async fn resolve_ticker_with_history_with_config(
+        ticker: &str,
+        candidate_ciks: &[u32],
+        config: &EdgarConfig,
+    ) -> Result<Vec<(u32, String)>, EDGARParserError> {
+        let index = TickerIndex::cached(config).await?;
+        let current = index
+            .get(ticker)
+            .ok_or_else(|| EDGARParserError::NotFound(format!("Ticker {} not found", ticker)))?;
+
+        let mut holders = vec![(current.cik_str, current.title.clone())];
+
+        for &cik in candidate_ciks.iter().filter(|&&cik| cik != current.cik_str) {
+            let body = fetch_http_body_with_config(
+                &submissions_url_at(&config.data_base_url, cik),
+                config,
+            )
             .await
             .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+            let submissions: Submissions = parse_json(&body, config)?;
 
-        // Deserialize JSON into a hashmap
-        let tickers: HashMap<String, CompanyData> = serde_json::from_str(&json_body)?;
+            if let Some(name) = submissions.name_while_holding(ticker) {
+                holders.push((cik, name));
+            }
+        }
 
-        tickers
-            .iter()
-            .find(|(_, c)| c.ticker == ticker)
-            .map(|(_, c)| EdgarParser {
+        Ok(holders)
+    }
+
+    /// Checks a user's ticker list against the SEC ticker file, downloading it once.
+    /// Unknown tickers come with suggestions of similarly listed companies.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the
+    /// ticker file cannot be fetched.
+    pub async fn validate_watchlist(tickers: &[&str]) -> Result<WatchlistReport, EDGARParserError> {
+        Self::validate_watchlist_with_config(tickers, &EdgarConfig::default()).await
+    }
+
+    /// Checks a ticker list as `validate_watchlist` does, using the ticker URLs in `config`.
+    pub async fn validate_watchlist_with_config(
+        tickers: &[&str],
+        config: &EdgarConfig,
+    ) -> Result<WatchlistReport, EDGARParserError> {
+        let index = TickerIndex::cached(config).await?;
+        Ok(WatchlistReport::from_index(tickers, &index))
+    }
+
+    /// Searches the SEC ticker file for companies whose title or ticker contains `query`,
+    /// ignoring case, downloading the file once. Results are ordered best match first; see
+    /// `TickerIndex::search`.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the
+    /// ticker file cannot be fetched.
+    pub async fn search_by_name(query: &str) -> Result<Vec<CompanyData>, EDGARParserError> {
+        Self::search_by_name_with_config(query, &EdgarConfig::default()).await
+    }
+
+    /// Searches the ticker file as `search_by_name` does, using the ticker URLs in `config`.
+    pub async fn search_by_name_with_config(
+        query: &str,
+        config: &EdgarConfig,
+    ) -> Result<Vec<CompanyData>, EDGARParserError> {
+        let index = TickerIndex::cached(config).await?;
+        Ok(index.search(query))
+    }
+
+    /// Creates an `EdgarParser` from an already loaded `TickerIndex`, without any network access.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the ticker is not in the index.
+    pub fn create_from_ticker_index(
+        ticker: &str,
+        index: &TickerIndex,
+        config: &EdgarConfig,
+    ) -> Result<EdgarParser, EDGARParserError> {
+        index
+            .get(ticker)
+            .map(|c| EdgarParser {
                 cik_str: Some(c.cik_str),
                 ticker: Some(c.ticker.clone()),
                 title: Some(c.title.clone()),
-                leading_zero_cik: format!("{:010}", c.cik_str),
+                leading_zero_cik: format!("{:0width$}", c.cik_str, width = SEC_CIK_WIDTH),
                 submissions: None,
                 company_facts: None,
+                config: config.clone(),
             })
             .ok_or_else(|| EDGARParserError::NotFound(format!("Ticker {} not found", ticker)))
     }
 
+    /// Creates an `EdgarParser` for a company known only by its CIK, e.g. a foreign private
+    /// issuer or fund without a ticker. The ticker and title are filled in from the ticker
+    /// file when it lists the CIK, choosing the first ticker alphabetically for companies with
+    /// several share classes. Otherwise the CIK is confirmed with `cik_exists` and the ticker
+    /// and title are left unset.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the SEC has no submissions for the CIK, or
+    /// `EDGARParserError::HttpError` if the existence check fails.
+    pub async fn create_from_cik(cik: u32) -> Result<EdgarParser, EDGARParserError> {
+        Self::create_from_cik_with_config(cik, &EdgarConfig::default()).await
+    }
+
+    /// Creates an `EdgarParser` for `cik` as `create_from_cik` does, using the URLs in `config`.
+    /// A ticker file that cannot be fetched only skips the enrichment.
+    pub async fn create_from_cik_with_config(
+        cik: u32,
+        config: &EdgarConfig,
+    ) -> Result<EdgarParser, EDGARParserError> {
+        let listed = match TickerIndex::cached(config).await {
+            Ok(index) => index.get_by_cik(cik).first().map(|&c| c.clone()),
+            Err(e) => {
+                log::warn!("Ticker file unavailable, not enriching CIK {}: {}", cik, e);
+                None
+            }
+        };
+
+        if listed.is_none() && !Self::cik_exists_with_config(cik, config).await? {
+            return Err(EDGARParserError::NotFound(format!("CIK {} not found", cik)));
+        }
+
+        Ok(EdgarParser {
+            ticker: listed.as_ref().map(|c| c.ticker.clone()),
+            title: listed.map(|c| c.title),
+            config: config.clone(),
+            ..Self::from_cik_unchecked(cik)
+        })
+    }
+
+    /// Creates an `EdgarParser` for `cik` directly, with no network access or validation. The
+    /// ticker and title are left unset.
+    ///
+    /// The caller guarantees that `cik` belongs to an SEC registrant. An unknown CIK is not
+    /// detected here; later fetches for it fail with `EDGARParserError::HttpError`. Use
+    /// `cik_exists` first when the CIK comes from an untrusted source.
+    pub fn from_cik_unchecked(cik: u32) -> EdgarParser {
+        EdgarParser {
+            cik_str: Some(cik),
+            ticker: None,
+            title: None,
+            leading_zero_cik: format!("{:0width$}", cik, width = SEC_CIK_WIDTH),
+            submissions: None,
+            company_facts: None,
+            config: EdgarConfig::default(),
+        }
+    }
+
+    /// Checks whether the SEC has submissions for `cik` with a HEAD request, which is far
+    /// cheaper than downloading the company facts or submissions themselves.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` if the request fails or the SEC answers with a
+    /// status other than 200 or 404.
+    pub async fn cik_exists(cik: u32) -> Result<bool, EDGARParserError> {
+        Self::cik_exists_with_config(cik, &EdgarConfig::default()).await
+    }
+
+    /// Checks whether `cik` exists against the data API base URL in `config`.
+    /// See `cik_exists`.
+    pub async fn cik_exists_with_config(
+        cik: u32,
+        config: &EdgarConfig,
+    ) -> Result<bool, EDGARParserError> {
+        let url = submissions_url_at(&config.data_base_url, cik);
+
+        let status = fetch_http_status(&url, config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+        match status {
+            reqwest::StatusCode::OK => Ok(true),
+            reqwest::StatusCode::NOT_FOUND => Ok(false),
+            other => Err(EDGARParserError::HttpError(
+                format!("Unexpected status checking CIK {}: {}", cik, other).into(),
+            )),
+        }
+    }
+
+    /// Looks up the exchange a ticker is listed on (e.g. `Nasdaq`, `NYSE`) from the SEC's
+    /// `company_tickers_exchange.json`, without fetching the company's submissions.
+    /// Returns `None` if the ticker is not listed or has no exchange.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` if the request fails, or
+    /// `EDGARParserError::JSONParseError` / `EDGARParserError::UnexpectedSchema` if the file
+    /// cannot be read.
+    pub async fn exchange_for_ticker(ticker: &str) -> Result<Option<String>, EDGARParserError> {
+        Self::exchange_for_ticker_with_config(ticker, &EdgarConfig::default()).await
+    }
+
+    /// Looks up a ticker's exchange as `exchange_for_ticker` does, using the URL in `config`.
+    pub async fn exchange_for_ticker_with_config(
+        ticker: &str,
+        config: &EdgarConfig,
+    ) -> Result<Option<String>, EDGARParserError> {
+        let body = fetch_http_body_with_config(&config.ticker_exchange_url, config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+        let file: TickerExchangeFile = parse_json(&body, config)?;
+
+        file.exchange_for(ticker)
+    }
+
+    /// Fetches the raw ticker file, falling back to each configured mirror when a request fails.
+    pub(crate) async fn fetch_ticker_file(
+        config: &EdgarConfig,
+    ) -> Result<String, EDGARParserError> {
+        let mut last_error = None;
+
+        for url in config.ticker_urls() {
+            match fetch_http_body_with_config(url, config).await {
+                Ok(body) => return Ok(body),
+                Err(e) => {
+                    log::warn!("Fetching ticker file from {} failed: {}", url, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(EDGARParserError::HttpError(
+            last_error.unwrap_or_else(|| "No ticker URLs configured".into()),
+        ))
+    }
+
+    /// Returns the CIK zero-padded to the configured `cik_pad_width`, for display or for
+    /// tools that expect a non-standard width. SEC endpoints always use `leading_zero_cik`.
+    pub fn padded_cik(&self) -> Option<String> {
+        self.cik_str
+            .map(|cik| format!("{:0width$}", cik, width = self.config.cik_pad_width))
+    }
+
+    /// Returns the company's page on the EDGAR website, listing its 40 most recent filings,
+    /// for "view on SEC" links. Always points at sec.gov, whatever URLs `config` overrides.
+    pub fn company_page_url(&self) -> String {
+        format!(
+            "{}?action=getcompany&CIK={}&type=&dateb=&owner=include&count=40",
+            BROWSE_EDGAR_URL, self.leading_zero_cik
+        )
+    }
+
+    /// Formats the Company Facts endpoint for the current company.
+    fn company_facts_url(&self) -> String {
+        data_sec_url_at(
+            &self.config.data_base_url,
+            &format!("api/xbrl/companyfacts/CIK{}.json", self.leading_zero_cik),
+        )
+    }
+
+    /// Formats the Company Concept endpoint for `taxonomy` and `concept` for the current company.
+    fn company_concept_url(&self, taxonomy: &str, concept: &str) -> String {
+        data_sec_url_at(
+            &self.config.data_base_url,
+            &format!(
+                "api/xbrl/companyconcept/CIK{}/{}/{}.json",
+                self.leading_zero_cik, taxonomy, concept
+            ),
+        )
+    }
+
+    /// Formats the Company Submissions endpoint for the current company.
+    fn submissions_url(&self) -> String {
+        data_sec_url_at(
+            &self.config.data_base_url,
+            &format!("submissions/CIK{}.json", self.leading_zero_cik),
+        )
+    }
+
     /// Fetches the SEC Company Facts XBRL JSON for the current company.
     ///
     /// # Errors
@@ -86,15 +537,11 @@ impl EdgarParser {
             ));
         }
 
-        let body_response = fetch_http_body(&format!(
-            "data.sec.gov/api/xbrl/companyfacts/CIK{}.json",
-            self.leading_zero_cik
-        ))
-        .await
-        .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+        let body_response = fetch_http_body_with_config(&self.company_facts_url(), &self.config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
 
-        let json_response: serde_json::Value =
-            serde_json::from_str(&body_response).map_err(EDGARParserError::JSONParseError)?;
+        let json_response: serde_json::Value = parse_json(&body_response, &self.config)?;
 
         // Store the company facts data in the struct
         self.company_facts = Some(json_response.clone());
@@ -102,6 +549,85 @@ impl EdgarParser {
         Ok(json_response)
     }
 
+    /// Fetches the SEC Company Facts XBRL JSON for the current company, like
+    /// [`fetch_company_facts`](Self::fetch_company_facts), and deserializes it into
+    /// [`CompanyFacts`]. Concepts reported in several units, e.g. `USD` and `USD-per-shares`,
+    /// keep each unit's values apart. The raw JSON is stored on the parser as well.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the request
+    /// fails or the JSON does not match the expected structure.
+    pub async fn fetch_company_facts_typed(&mut self) -> Result<CompanyFacts, EDGARParserError> {
+        let json_response = self.fetch_company_facts().await?;
+
+        Ok(serde_json::from_value(json_response)?)
+    }
+
+    /// Fetches the SEC Company Concept XBRL JSON for one concept of the current company, e.g.
+    /// `("us-gaap", "Revenues")`. It holds every value the company reported for the concept, a
+    /// fraction of the size of the full company facts when only one concept is needed.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the request
+    /// fails, e.g. with a 404 when the company never reported the concept.
+    pub async fn fetch_company_concept(
+        &self,
+        taxonomy: &str,
+        concept: &str,
+    ) -> Result<serde_json::Value, EDGARParserError> {
+        if self.leading_zero_cik.is_empty() {
+            return Err(EDGARParserError::NotFound(
+                "Leading zero CIK is not set. Call create_from_ticker first.".to_string(),
+            ));
+        }
+
+        let body_response =
+            fetch_http_body_with_config(&self.company_concept_url(taxonomy, concept), &self.config)
+                .await
+                .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+        parse_json(&body_response, &self.config)
+    }
+
+    /// Returns the time series of `concept` in `unit` from the stored company facts, e.g.
+    /// `("us-gaap", "Revenues", "USD")`, ordered by period end. Periods reported by more than
+    /// one filing keep the value from the most recently filed one, so restated comparatives
+    /// replace the originals. Call [`fetch_company_facts`](Self::fetch_company_facts) first.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the company facts have not been fetched or the
+    /// concept is not reported in `unit`, and `EDGARParserError::JSONParseError` if the concept
+    /// does not match the expected structure.
+    pub fn concept_series(
+        &self,
+        taxonomy: &str,
+        concept: &str,
+        unit: &str,
+    ) -> Result<Vec<FactPoint>, EDGARParserError> {
+        let company_facts = self.company_facts.as_ref().ok_or_else(|| {
+            EDGARParserError::NotFound(
+                "Company facts not fetched. Call fetch_company_facts first.".to_string(),
+            )
+        })?;
+        let raw_concept = company_facts
+            .get("facts")
+            .and_then(|facts| facts.get(taxonomy))
+            .and_then(|facts| facts.get(concept))
+            .ok_or_else(|| {
+                EDGARParserError::NotFound(format!("Concept {}/{} not reported", taxonomy, concept))
+            })?;
+
+        let series = Concept::deserialize(raw_concept)?.series(unit);
+        if series.is_empty() {
+            return Err(EDGARParserError::NotFound(format!(
+                "Concept {}/{} has no values in {}",
+                taxonomy, concept, unit
+            )));
+        }
+
+        Ok(series)
+    }
+
     /// Fetches the SEC Company Submissions JSON for the current company. This JSON data structure contains metadata such as current name,
     /// former name, and stock exchanges and ticker symbols of publicly-traded companies. The object’s property path contains at least one year’s of
     /// filing or to 1,000 (whichever is more) of the most recent filings in a compact columnar data array. If the entity has additional filings, files
@@ -116,15 +642,11 @@ impl EdgarParser {
             ));
         }
 
-        let body_response = fetch_http_body(&format!(
-            "data.sec.gov/submissions/CIK{}.json",
-            self.leading_zero_cik
-        ))
-        .await
-        .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+        let body_response = fetch_http_body_with_config(&self.submissions_url(), &self.config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
 
-        let json_response: serde_json::Value =
-            serde_json::from_str(&body_response).map_err(EDGARParserError::JSONParseError)?;
+        let json_response: serde_json::Value = parse_json(&body_response, &self.config)?;
 
         // Store the submissions data in the struct
         self.submissions = Some(json_response.clone());
@@ -132,6 +654,393 @@ impl EdgarParser {
         Ok(json_response)
     }
 
+    /// Fetches the SEC Company Submissions JSON for the current company, like
+    /// [`fetch_submissions`](Self::fetch_submissions), and deserializes it into [`Submissions`].
+    /// The raw JSON is stored on the parser as well, so later calls can reuse it.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the request
+    /// fails or the JSON does not match the expected structure.
+    pub async fn fetch_submissions_typed(&mut self) -> Result<Submissions, EDGARParserError> {
+        let json_response = self.fetch_submissions().await?;
+
+        Ok(serde_json::from_value(json_response)?)
+    }
+
+    /// Returns every filing the company has ever made, oldest first. The recent filings of the
+    /// submissions JSON are combined with each supplemental file listed under `filings.files`,
+    /// which are fetched one at a time to respect the SEC rate limit. Previously fetched
+    /// submissions are reused; otherwise they are fetched first.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if a request fails,
+    /// or `EDGARParserError::UnexpectedSchema` if a filings block has columns of differing length.
+    pub async fn all_filings(&mut self) -> Result<Vec<Filing>, EDGARParserError> {
+        let submissions_json = match &self.submissions {
+            Some(submissions) => submissions.clone(),
+            None => self.fetch_submissions().await?,
+        };
+        let submissions: Submissions = serde_json::from_value(submissions_json)?;
+
+        let mut filings = submissions.filings.recent.to_filings()?;
+
+        for file in &submissions.filings.files {
+            let url = data_sec_url_at(
+                &self.config.data_base_url,
+                &format!("submissions/{}", file.name),
+            );
+            let body = fetch_http_body_with_config(&url, &self.config)
+                .await
+                .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+            let supplemental: RecentFilings = parse_json(&body, &self.config)?;
+
+            filings.extend(supplemental.to_filings()?);
+        }
+
+        // A stable sort keeps same-day filings in a consistent order.
+        filings.sort_by(|a, b| a.filing_date.cmp(&b.filing_date));
+
+        Ok(filings)
+    }
+
+    /// Downloads and parses the company's recent Form 4 and Form 5 filings (and their
+    /// amendments), returning every reported insider transaction, newest filing first. Form 3
+    /// filings only report initial holdings and are skipped. `since` limits the filings to
+    /// those filed on or after that date. Previously fetched submissions are reused; otherwise
+    /// they are fetched first. Only the recent filings of the submissions JSON are searched,
+    /// and the ownership documents are fetched one at a time to respect the SEC rate limit.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the CIK is not set,
+    /// `EDGARParserError::HttpError` / `EDGARParserError::JSONParseError` if a request fails, or
+    /// `EDGARParserError::XMLParseError` if an ownership document cannot be parsed.
+    pub async fn insider_transactions(
+        &mut self,
+        since: Option<NaiveDate>,
+    ) -> Result<Vec<Transaction>, EDGARParserError> {
+        let submissions_json = match &self.submissions {
+            Some(submissions) => submissions.clone(),
+            None => self.fetch_submissions().await?,
+        };
+        let submissions: Submissions = serde_json::from_value(submissions_json)?;
+
+        let cik = self.cik_str.ok_or_else(|| {
+            EDGARParserError::NotFound("CIK is not set. Call create_from_ticker first.".to_string())
+        })?;
+
+        let filings = submissions.recent_filings().filter(|f| {
+            INSIDER_TRANSACTION_FORMS.contains(&f.form.as_str())
+                && since.is_none_or(|since| {
+                    NaiveDate::parse_from_str(&f.filing_date, "%Y-%m-%d")
+                        .is_ok_and(|filed| filed >= since)
+                })
+        });
+
+        let mut transactions = Vec::new();
+        for filing in filings {
+            let primary_document = self
+                .resolve_primary_document(cik, &filing, FilingIndex::ownership_document_name)
+                .await?;
+            let url = format!(
+                "{}/{}/{}/{}",
+                self.config.archives_base_url,
+                cik,
+                filing.accession_number.replace('-', ""),
+                raw_ownership_document(&primary_document)
+            );
+            let xml = fetch_http_body_with_config(&url, &self.config)
+                .await
+                .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+            transactions.extend(parse_ownership_document(&xml)?);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Finds the company's most recent 10-K, downloads its primary document and returns the
+    /// document's visible text with the HTML markup stripped. Previously fetched submissions
+    /// are reused; otherwise they are fetched first.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the company has no recent 10-K, or
+    /// `EDGARParserError::HttpError` / `EDGARParserError::JSONParseError` if a request fails.
+    pub async fn fetch_latest_10k_text(&mut self) -> Result<String, EDGARParserError> {
+        let submissions_json = match &self.submissions {
+            Some(submissions) => submissions.clone(),
+            None => self.fetch_submissions().await?,
+        };
+        let submissions: Submissions = serde_json::from_value(submissions_json)?;
+
+        let cik = self.cik_str.ok_or_else(|| {
+            EDGARParserError::NotFound("CIK is not set. Call create_from_ticker first.".to_string())
+        })?;
+
+        let filing = submissions.latest_filing("10-K").ok_or_else(|| {
+            EDGARParserError::NotFound(format!("No 10-K filing found for CIK {}", cik))
+        })?;
+
+        let filing = Filing {
+            primary_document: self
+                .resolve_primary_document(cik, &filing, FilingIndex::primary_document_name)
+                .await?,
+            ..filing
+        };
+        let document = fetch_filing_text_with_config(
+            &filing.primary_document_url_at(&self.config.archives_base_url, cik),
+            &self.config,
+        )
+        .await
+        .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+        Ok(html_to_text(&document))
+    }
+
+    /// Downloads the primary document of `filing` into `dir`, arranged according to `layout`,
+    /// and returns the path it was written to. The document is saved byte for byte, in its
+    /// original encoding, and any missing folders are created.
+    /// A filing without a primary document in its submissions entry falls back to the one
+    /// picked from its index.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the CIK is not set or no primary document can be
+    /// found, even in the filing index, `EDGARParserError::HttpError` if a request fails, or
+    /// `EDGARParserError::IoError` if the file cannot be written.
+    pub async fn download_filing(
+        &self,
+        filing: &Filing,
+        dir: impl AsRef<Path>,
+        layout: DownloadLayout,
+    ) -> Result<PathBuf, EDGARParserError> {
+        let cik = self.cik_str.ok_or_else(|| {
+            EDGARParserError::NotFound("CIK is not set. Call create_from_ticker first.".to_string())
+        })?;
+        let filing = &Filing {
+            primary_document: self
+                .resolve_primary_document(cik, filing, FilingIndex::primary_document_name)
+                .await?,
+            ..filing.clone()
+        };
+
+        let url = filing.primary_document_url_at(&self.config.archives_base_url, cik);
+        let (bytes, _headers) = fetch_bytes_with_headers(&url, &self.config).await?;
+
+        let path = layout.path_for(
+            dir.as_ref(),
+            cik,
+            &filing.accession_number,
+            &filing.primary_document,
+        );
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, bytes)?;
+
+        Ok(path)
+    }
+
+    /// Downloads the Company Facts JSON of every CIK in `ciks` into `dest_dir`, one
+    /// `CIK##########.json` file per company as in the SEC's bulk archive. Files that already
+    /// exist are skipped, so an interrupted run can be resumed by calling it again; each file
+    /// is written under a temporary name and renamed once complete, so a partial download is
+    /// never mistaken for a finished one.
+    ///
+    /// A CIK that fails to download is recorded in the report and the run continues.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::IoError` if `dest_dir` cannot be created.
+    pub async fn download_facts_index(
+        ciks: &[u32],
+        dest_dir: &Path,
+    ) -> Result<DownloadReport, EDGARParserError> {
+        Self::download_facts_index_with_config(ciks, dest_dir, &EdgarConfig::default()).await
+    }
+
+    /// Downloads Company Facts as `download_facts_index` does, using the data API URL in `config`.
+    pub async fn download_facts_index_with_config(
+        ciks: &[u32],
+        dest_dir: &Path,
+        config: &EdgarConfig,
+    ) -> Result<DownloadReport, EDGARParserError> {
+        fs::create_dir_all(dest_dir)?;
+
+        let mut report = DownloadReport::default();
+        for &cik in ciks {
+            let path = dest_dir.join(format!("CIK{:0width$}.json", cik, width = SEC_CIK_WIDTH));
+            if path.exists() {
+                report.skipped.push(cik);
+                continue;
+            }
+
+            let url = company_facts_url_at(&config.data_base_url, cik);
+            let result = match fetch_bytes_with_headers(&url, config).await {
+                Ok((bytes, _headers)) => {
+                    let partial = path.with_extension("json.part");
+                    fs::write(&partial, bytes)
+                        .and_then(|_| fs::rename(&partial, &path))
+                        .map_err(EDGARParserError::from)
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(()) => report.downloaded.push(cik),
+                Err(e) => {
+                    log::warn!("Failed to download company facts for CIK {}: {}", cik, e);
+                    report.failed.push((cik, e.to_string()));
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Returns the primary document of `filing`, or for older filings whose submissions entry
+    /// has none, the document `pick` chooses from the filing's `index.json`, e.g.
+    /// `FilingIndex::primary_document_name`.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the index lists no usable document, or
+    /// `EDGARParserError::HttpError` / `EDGARParserError::JSONParseError` if the index request
+    /// fails.
+    async fn resolve_primary_document(
+        &self,
+        cik: u32,
+        filing: &Filing,
+        pick: fn(&FilingIndex) -> Option<&str>,
+    ) -> Result<String, EDGARParserError> {
+        if !filing.primary_document.is_empty() {
+            return Ok(filing.primary_document.clone());
+        }
+
+        let index_url = format!(
+            "{}/{}/{}/index.json",
+            self.config.archives_base_url,
+            cik,
+            filing.accession_number.replace('-', "")
+        );
+        let index_body = fetch_http_body_with_config(&index_url, &self.config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+        let index: FilingIndex = parse_json(&index_body, &self.config)?;
+
+        pick(&index).map(str::to_string).ok_or_else(|| {
+            EDGARParserError::NotFound(format!(
+                "Filing {} has no primary document",
+                filing.accession_number
+            ))
+        })
+    }
+
+    /// Locates the XBRL instance document of the filing `accession` (e.g. `0000320193-23-000106`)
+    /// from the filing's `index.json` and returns it as raw XML. For inline-XBRL filings this is
+    /// the instance the SEC extracts from the primary document.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::NotFound` if the CIK is not set or the filing has no XBRL
+    /// instance, or `EDGARParserError::HttpError` / `EDGARParserError::JSONParseError` if a
+    /// request fails.
+    pub async fn fetch_xbrl_instance(&self, accession: &str) -> Result<String, EDGARParserError> {
+        let cik = self.cik_str.ok_or_else(|| {
+            EDGARParserError::NotFound("CIK is not set. Call create_from_ticker first.".to_string())
+        })?;
+        let folder = format!(
+            "{}/{}/{}",
+            self.config.archives_base_url,
+            cik,
+            accession.replace('-', "")
+        );
+
+        let index_body =
+            fetch_http_body_with_config(&format!("{}/index.json", folder), &self.config)
+                .await
+                .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+        let index: FilingIndex = parse_json(&index_body, &self.config)?;
+
+        let instance = index.xbrl_instance_name().ok_or_else(|| {
+            EDGARParserError::NotFound(format!("No XBRL instance found in filing {}", accession))
+        })?;
+
+        fetch_http_body_with_config(&format!("{}/{}", folder, instance), &self.config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))
+    }
+
+    /// Streams the entries of the quarterly `master.idx` full index, parsing it line by line as
+    /// it downloads rather than buffering the whole file.
+    ///
+    /// # Arguments
+    /// - `year`: Calendar year of the index, e.g. `2024`.
+    /// - `quarter`: Calendar quarter of the index, `1` through `4`.
+    /// - `form`: Form type to restrict the entries to, or `None` for every form.
+    ///
+    /// The request is sent when the stream is first polled; request and read failures are
+    /// yielded as `EDGARParserError::HttpError` and end the stream.
+    pub fn stream_quarterly_index(
+        year: u16,
+        quarter: u8,
+        form: Option<FilingTypeOption>,
+    ) -> impl Stream<Item = Result<IndexEntry, EDGARParserError>> + use<> {
+        Self::stream_quarterly_index_with_config(year, quarter, form, &EdgarConfig::default())
+    }
+
+    /// Streams a quarterly index as `stream_quarterly_index` does, using the URLs in `config`.
+    pub fn stream_quarterly_index_with_config(
+        year: u16,
+        quarter: u8,
+        form: Option<FilingTypeOption>,
+        config: &EdgarConfig,
+    ) -> impl Stream<Item = Result<IndexEntry, EDGARParserError>> + use<> {
+        let url = format!(
+            "{}/{}/QTR{}/master.idx",
+            config.full_index_base_url, year, quarter
+        );
+        stream_master_index(url, form.map(|f| f.to_string()), config)
+    }
+
+    /// Fetches the EDGAR current-events feed: the latest filings across all companies,
+    /// optionally restricted to a single form type, newest first.
+    ///
+    /// # Arguments
+    /// - `form`: Form type to restrict the feed to, or `None` for every form.
+    /// - `count`: Number of entries to request (the SEC accepts 10, 20, 40, 80 or 100).
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` if the request fails or
+    /// `EDGARParserError::XMLParseError` if the feed cannot be parsed.
+    pub async fn fetch_current_filings(
+        form: Option<FilingTypeOption>,
+        count: u32,
+    ) -> Result<Vec<FilingEntry>, EDGARParserError> {
+        Self::fetch_current_filings_with_config(form, count, &EdgarConfig::default()).await
+    }
+
+    /// Fetches the EDGAR current-events feed from the browse URL in `config`.
+    /// See `fetch_current_filings`.
+    pub async fn fetch_current_filings_with_config(
+        form: Option<FilingTypeOption>,
+        count: u32,
+        config: &EdgarConfig,
+    ) -> Result<Vec<FilingEntry>, EDGARParserError> {
+        let form_string = form.map(|f| f.to_string()).unwrap_or_default();
+
+        let mut url = Url::parse(&config.browse_edgar_url)?;
+        url.query_pairs_mut()
+            .append_pair("action", "getcurrent")
+            .append_pair("type", &form_string)
+            .append_pair("company", "")
+            .append_pair("dateb", "")
+            .append_pair("owner", "include")
+            .append_pair("count", &count.to_string())
+            .append_pair("output", "atom");
+
+        let body = fetch_http_body_with_config(url.as_str(), config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+        parse_atom_feed(&body)
+    }
+
     /// The xbrl/frames API aggregates one fact for each reporting entity that is
     /// last filed and most closely fits the calendrical period requested. This API
     /// supports annual, quarterly, and instantaneous data:
@@ -140,10 +1049,10 @@ impl EdgarParser {
     /// a denominator, these are separated by “-per-” such as “USD-per-shares”.
     /// Note that the default unit in XBRL is “pure”.
     ///
-    /// The period format is:
-    /// - CY#### for annual data (duration 365 days +/- 30 days),
-    /// - CY####Q# for quarterly data (duration 91 days +/- 30 days), and
-    /// - CY####Q#I for instantaneous data.
+    /// The period format, selected by the `Period` variant, is:
+    /// - CY#### for annual data (duration 365 days +/- 30 days), `Period::Annual`,
+    /// - CY####Q# for quarterly data (duration 91 days +/- 30 days), `Period::Quarterly`, and
+    /// - CY####Q#I for instantaneous data, `Period::Instantaneous`.
     ///
     /// Because company financial calendars can start and end on any month or day
     /// and even change in length from quarter to quarter according to the day of
@@ -155,22 +1064,207 @@ impl EdgarParser {
     pub async fn fetch_xbrl_frames(
         fact: &str,
         unit: &str,
-        year: &u16,
-        quarter: &u8,
+        period: Period,
     ) -> Result<serde_json::Value, EDGARParserError> {
-        let path = format!(
-            "data.sec.gov/api/xbrl/frames/us-gaap/{}/{}/CY{}{}I.json",
-            fact, unit, year, quarter,
-        );
+        Self::fetch_xbrl_frames_in(Taxonomy::UsGaap, fact, unit, period).await
+    }
+
+    /// Fetches a frame as `fetch_xbrl_frames` does, from `taxonomy` instead of us-gaap, e.g.
+    /// `Taxonomy::Dei` for `EntityCommonStockSharesOutstanding`.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the request fails.
+    pub async fn fetch_xbrl_frames_in(
+        taxonomy: Taxonomy,
+        fact: &str,
+        unit: &str,
+        period: Period,
+    ) -> Result<serde_json::Value, EDGARParserError> {
+        Self::fetch_xbrl_frames_in_with_config(
+            taxonomy,
+            fact,
+            unit,
+            period,
+            &EdgarConfig::default(),
+        )
+        .await
+    }
 
-        let body_response = fetch_http_body(&path)
+    /// Fetches a frame as `fetch_xbrl_frames_in` does, using the data API URL and client
+    /// settings in `config`.
+    pub async fn fetch_xbrl_frames_in_with_config(
+        taxonomy: Taxonomy,
+        fact: &str,
+        unit: &str,
+        period: Period,
+        config: &EdgarConfig,
+    ) -> Result<serde_json::Value, EDGARParserError> {
+        let url = frames_url_at(&config.data_base_url, taxonomy, fact, unit, period)?;
+        let body = fetch_http_body_with_config(url.as_str(), config)
             .await
             .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
 
-        let json_response: serde_json::Value =
-            serde_json::from_str(&body_response).map_err(EDGARParserError::JSONParseError)?;
+        parse_json(&body, config)
+    }
 
-        Ok(json_response)
+    /// Fetches the same frame as `fetch_xbrl_frames`, but streams the `data` array into
+    /// `on_datum` one `FrameDatum` at a time instead of building a `serde_json::Value` tree.
+    /// Returns the frame's metadata once every entry has been visited.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the request fails.
+    pub async fn fetch_xbrl_frames_streaming<F>(
+        fact: &str,
+        unit: &str,
+        period: Period,
+        on_datum: F,
+    ) -> Result<FrameHeader, EDGARParserError>
+    where
+        F: FnMut(FrameDatum),
+    {
+        Self::fetch_xbrl_frames_streaming_in(Taxonomy::UsGaap, fact, unit, period, on_datum).await
+    }
+
+    /// Streams a frame as `fetch_xbrl_frames_streaming` does, from `taxonomy` instead of
+    /// us-gaap, the same endpoints `fetch_xbrl_frames_in` reaches.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the request fails.
+    pub async fn fetch_xbrl_frames_streaming_in<F>(
+        taxonomy: Taxonomy,
+        fact: &str,
+        unit: &str,
+        period: Period,
+        on_datum: F,
+    ) -> Result<FrameHeader, EDGARParserError>
+    where
+        F: FnMut(FrameDatum),
+    {
+        Self::fetch_xbrl_frames_streaming_in_with_config(
+            taxonomy,
+            fact,
+            unit,
+            period,
+            on_datum,
+            &EdgarConfig::default(),
+        )
+        .await
+    }
+
+    /// Streams a frame as `fetch_xbrl_frames_streaming_in` does, using the data API URL and
+    /// client settings in `config`.
+    pub async fn fetch_xbrl_frames_streaming_in_with_config<F>(
+        taxonomy: Taxonomy,
+        fact: &str,
+        unit: &str,
+        period: Period,
+        on_datum: F,
+        config: &EdgarConfig,
+    ) -> Result<FrameHeader, EDGARParserError>
+    where
+        F: FnMut(FrameDatum),
+    {
+        let url = frames_url_at(&config.data_base_url, taxonomy, fact, unit, period)?;
+        let body = fetch_http_body_with_config(url.as_str(), config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+        stream_frames(body.as_bytes(), on_datum)
+    }
+
+    /// Fetches one frame per `(fact, unit)` pair for `period` and joins them by CIK, giving a
+    /// row of `fact -> value` for every entity that reported at least one of the facts.
+    /// Entities missing from a frame simply have no entry for that fact. Frames are fetched
+    /// one at a time to respect the SEC rate limit.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if any
+    /// request fails.
+    pub async fn fetch_frames_joined(
+        taxonomy: Taxonomy,
+        facts: &[(&str, &str)],
+        period: Period,
+    ) -> Result<HashMap<u32, HashMap<String, f64>>, EDGARParserError> {
+        Self::fetch_frames_joined_with_config(taxonomy, facts, period, &EdgarConfig::default())
+            .await
+    }
+
+    /// Fetches and joins frames as `fetch_frames_joined` does, using the data API URL in `config`.
+    pub async fn fetch_frames_joined_with_config(
+        taxonomy: Taxonomy,
+        facts: &[(&str, &str)],
+        period: Period,
+        config: &EdgarConfig,
+    ) -> Result<HashMap<u32, HashMap<String, f64>>, EDGARParserError> {
+        let mut rows: HashMap<u32, HashMap<String, f64>> = HashMap::new();
+
+        for (fact, unit) in facts {
+            let url = frames_url_at(&config.data_base_url, taxonomy, fact, unit, period)?;
+            let body = fetch_http_body_with_config(url.as_str(), config)
+                .await
+                .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+            stream_frames(body.as_bytes(), |datum| {
+                rows.entry(datum.cik)
+                    .or_default()
+                    .insert(fact.to_string(), datum.val);
+            })?;
+        }
+
+        Ok(rows)
+    }
+
+    /// Fetches the frame for `taxonomy`, `fact` and `unit` over `period` and keeps only the
+    /// entities in `ciks`, keyed by CIK. The SEC cannot filter frames server-side, so the
+    /// whole frame is downloaded once and streamed, holding on to the requested entities
+    /// only. CIKs that did not report the fact are absent from the result.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` or `EDGARParserError::JSONParseError` if the
+    /// request fails.
+    pub async fn fetch_xbrl_frames_for_ciks(
+        taxonomy: Taxonomy,
+        fact: &str,
+        unit: &str,
+        period: Period,
+        ciks: &[u32],
+    ) -> Result<HashMap<u32, FrameDatum>, EDGARParserError> {
+        Self::fetch_xbrl_frames_for_ciks_with_config(
+            taxonomy,
+            fact,
+            unit,
+            period,
+            ciks,
+            &EdgarConfig::default(),
+        )
+        .await
+    }
+
+    /// Fetches and filters a frame as `fetch_xbrl_frames_for_ciks` does, using the data API
+    /// URL in `config`.
+    pub async fn fetch_xbrl_frames_for_ciks_with_config(
+        taxonomy: Taxonomy,
+        fact: &str,
+        unit: &str,
+        period: Period,
+        ciks: &[u32],
+        config: &EdgarConfig,
+    ) -> Result<HashMap<u32, FrameDatum>, EDGARParserError> {
+        let wanted: HashSet<u32> = ciks.iter().copied().collect();
+        let mut data: HashMap<u32, FrameDatum> = HashMap::new();
+
+        let url = frames_url_at(&config.data_base_url, taxonomy, fact, unit, period)?;
+        let body = fetch_http_body_with_config(url.as_str(), config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+        stream_frames(body.as_bytes(), |datum| {
+            if wanted.contains(&datum.cik) {
+                data.insert(datum.cik, datum);
+            }
+        })?;
+
+        Ok(data)
     }
 }
 
@@ -185,6 +1279,26 @@ mod tests {
         assert_eq!(result, "0000123456");
     }
 
+    #[test]
+    fn test_company_data_accepts_numeric_or_string_cik() {
+        let from_number: CompanyData =
+            serde_json::from_str(r#"{"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."}"#)
+                .unwrap();
+        let from_string: CompanyData = serde_json::from_str(
+            r#"{"cik_str": "0000320193", "ticker": "AAPL", "title": "Apple Inc."}"#,
+        )
+        .unwrap();
+
+        assert_eq!(from_number, from_string);
+        assert_eq!(from_string.cik_str, 320193);
+        assert!(
+            serde_json::from_str::<CompanyData>(
+                r#"{"cik_str": "AAPL", "ticker": "AAPL", "title": "Apple Inc."}"#
+            )
+            .is_err()
+        );
+    }
+
     #[tokio::test]
     async fn test_new_success() {
         let ticker = "AAPL";
@@ -197,6 +1311,1171 @@ mod tests {
         result.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_create_from_ticker_falls_back_to_mirror() {
+        let mut primary = mockito::Server::new_async().await;
+        let mut mirror = mockito::Server::new_async().await;
+
+        let primary_mock = primary
+            .mock("GET", "/files/company_tickers.json")
+            .with_status(403)
+            .create_async()
+            .await;
+        let mirror_mock = mirror
+            .mock("GET", "/company_tickers.json")
+            .with_status(200)
+            .with_body(r#"{"0":{"cik_str":320193,"ticker":"AAPL","title":"Apple Inc."}}"#)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/files/company_tickers.json", primary.url()),
+            ticker_fallback_urls: vec![format!("{}/company_tickers.json", mirror.url())],
+            ..EdgarConfig::for_tests()
+        };
+
+        let parser = EdgarParser::create_from_ticker_with_config("AAPL", &config)
+            .await
+            .unwrap();
+        primary_mock.assert_async().await;
+        mirror_mock.assert_async().await;
+        assert_eq!(parser.cik_str, Some(320193));
+        assert_eq!(parser.leading_zero_cik, "0000320193");
+    }
+
+    #[tokio::test]
+    async fn test_create_from_cik() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/files/company_tickers.json")
+            .with_status(200)
+            .with_body(r#"{"0":{"cik_str":320193,"ticker":"AAPL","title":"Apple Inc."}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("HEAD", "/submissions/CIK0001067983.json")
+            .with_status(200)
+            .create_async()
+            .await;
+        server
+            .mock("HEAD", "/submissions/CIK0000000001.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/files/company_tickers.json", server.url()),
+            ticker_fallback_urls: Vec::new(),
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+
+        let apple = EdgarParser::create_from_cik_with_config(320193, &config)
+            .await
+            .unwrap();
+        assert_eq!(apple.leading_zero_cik, "0000320193");
+        assert_eq!(apple.ticker.as_deref(), Some("AAPL"));
+        assert_eq!(apple.title.as_deref(), Some("Apple Inc."));
+
+        let unlisted = EdgarParser::create_from_cik_with_config(1067983, &config)
+            .await
+            .unwrap();
+        assert_eq!(unlisted.leading_zero_cik, "0001067983");
+        assert_eq!(unlisted.ticker, None);
+
+        assert!(matches!(
+            EdgarParser::create_from_cik_with_config(1, &config).await,
+            Err(EDGARParserError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_by_name() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/files/company_tickers.json")
+            .with_status(200)
+            .with_body(
+                r#"{"0":{"cik_str":320193,"ticker":"AAPL","title":"Apple Inc."},"1":{"cik_str":789019,"ticker":"MSFT","title":"MICROSOFT CORP"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/files/company_tickers.json", server.url()),
+            ticker_fallback_urls: Vec::new(),
+            ..EdgarConfig::for_tests()
+        };
+
+        let results = EdgarParser::search_by_name_with_config("apple", &config)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Apple Inc.");
+        assert_eq!(results[0].cik_str, 320193);
+    }
+
+    #[tokio::test]
+    async fn test_ticker_file_is_cached_between_lookups() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker_mock = server
+            .mock("GET", "/files/cached_company_tickers.json")
+            .with_status(200)
+            .with_body(
+                r#"{"0":{"cik_str":320193,"ticker":"AAPL","title":"Apple Inc."},"1":{"cik_str":789019,"ticker":"MSFT","title":"MICROSOFT CORP"}}"#,
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        // Mock servers are reused between tests, so a path of its own keeps this test's
+        // cache entry apart from theirs.
+        let config = EdgarConfig {
+            ticker_url: format!("{}/files/cached_company_tickers.json", server.url()),
+            ticker_fallback_urls: Vec::new(),
+            cache_ticker_file: true,
+            ..EdgarConfig::for_tests()
+        };
+
+        let apple = EdgarParser::create_from_ticker_with_config("AAPL", &config)
+            .await
+            .unwrap();
+        let microsoft = EdgarParser::create_from_ticker_with_config("MSFT", &config)
+            .await
+            .unwrap();
+        assert_eq!(apple.cik_str, Some(320193));
+        assert_eq!(microsoft.cik_str, Some(789019));
+
+        EdgarParser::refresh_ticker_cache_with_config(&config)
+            .await
+            .unwrap();
+        EdgarParser::create_from_ticker_with_config("AAPL", &config)
+            .await
+            .unwrap();
+
+        // One download for the first lookup and one for the refresh.
+        ticker_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_from_ticker_all_mirrors_fail() {
+        let mut primary = mockito::Server::new_async().await;
+        primary
+            .mock("GET", "/company_tickers.json")
+            .with_status(403)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/company_tickers.json", primary.url()),
+            ticker_fallback_urls: vec![format!("{}/company_tickers.json", primary.url())],
+            ..EdgarConfig::for_tests()
+        };
+
+        let result = EdgarParser::create_from_ticker_with_config("AAPL", &config).await;
+        assert!(matches!(result, Err(EDGARParserError::HttpError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_captures_body_when_enabled() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/company_tickers.json")
+            .with_status(200)
+            .with_body("<html>Your Request Originates from an Undeclared Automated Tool</html>")
+            .create_async()
+            .await;
+
+        let mut config = EdgarConfig {
+            ticker_url: format!("{}/company_tickers.json", server.url()),
+            ..EdgarConfig::for_tests()
+        };
+
+        let result = EdgarParser::create_from_ticker_with_config("AAPL", &config).await;
+        assert!(matches!(result, Err(EDGARParserError::JSONParseError(_))));
+
+        config.capture_body_on_error = true;
+        let result = EdgarParser::create_from_ticker_with_config("AAPL", &config).await;
+        match result {
+            Err(err @ EDGARParserError::JSONParseErrorWithBody { .. }) => {
+                assert!(err.to_string().contains("Undeclared Automated Tool"));
+            }
+            other => panic!("Expected JSONParseErrorWithBody, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_json_truncates_snippet() {
+        let config = EdgarConfig {
+            capture_body_on_error: true,
+            ..EdgarConfig::for_tests()
+        };
+        let body = "x".repeat(BODY_SNIPPET_LEN * 2);
+
+        let result: Result<serde_json::Value, _> = parse_json(&body, &config);
+        match result {
+            Err(EDGARParserError::JSONParseErrorWithBody { body_snippet, .. }) => {
+                assert_eq!(body_snippet.len(), BODY_SNIPPET_LEN);
+            }
+            other => panic!("Expected JSONParseErrorWithBody, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_padded_cik_override_keeps_sec_urls_ten_wide() {
+        let parser = EdgarParser {
+            cik_str: Some(320193),
+            ticker: Some("AAPL".to_string()),
+            title: Some("Apple Inc.".to_string()),
+            submissions: None,
+            company_facts: None,
+            leading_zero_cik: "0000320193".to_string(),
+            config: EdgarConfig {
+                cik_pad_width: 12,
+                ..EdgarConfig::for_tests()
+            },
+        };
+
+        assert_eq!(parser.padded_cik().unwrap(), "000000320193");
+        assert!(parser.company_facts_url().ends_with("/CIK0000320193.json"));
+        assert!(parser.submissions_url().ends_with("/CIK0000320193.json"));
+    }
+
+    #[test]
+    fn test_company_page_url() {
+        let parser = EdgarParser::from_cik_unchecked(320193);
+
+        assert_eq!(
+            parser.company_page_url(),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=0000320193&type=&dateb=&owner=include&count=40"
+        );
+    }
+
+    #[test]
+    fn test_data_sec_urls_have_https_scheme() {
+        let parser = EdgarParser::from_cik_unchecked(320193);
+
+        assert_eq!(
+            parser.company_facts_url(),
+            "https://data.sec.gov/api/xbrl/companyfacts/CIK0000320193.json"
+        );
+        assert_eq!(
+            parser.submissions_url(),
+            "https://data.sec.gov/submissions/CIK0000320193.json"
+        );
+    }
+
+    #[test]
+    fn test_company_concept_url() {
+        let parser = EdgarParser::from_cik_unchecked(320193);
+
+        assert_eq!(
+            parser.company_concept_url("us-gaap", "Revenues"),
+            "https://data.sec.gov/api/xbrl/companyconcept/CIK0000320193/us-gaap/Revenues.json"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires network access to data.sec.gov"]
+    async fn test_fetch_company_concept_live() {
+        let parser = EdgarParser::from_cik_unchecked(320193);
+        let concept = parser
+            .fetch_company_concept("us-gaap", "Revenues")
+            .await
+            .unwrap();
+
+        assert_eq!(concept["cik"], 320193);
+        assert_eq!(concept["tag"], "Revenues");
+        assert!(concept["units"]["USD"].is_array());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_xbrl_frames_in_requests_taxonomy() {
+        let body = r#"{"taxonomy": "dei", "tag": "EntityCommonStockSharesOutstanding", "ccp": "CY2023Q4I", "uom": "shares", "pts": 2, "data": [
+            {"accn": "0000320193-24-000006", "cik": 320193, "entityName": "Apple Inc.", "loc": "US-CA", "end": "2024-01-19", "val": 15441881000},
+            {"accn": "0000789019-24-000008", "cik": 789019, "entityName": "MICROSOFT CORP", "loc": "US-WA", "end": "2024-01-23", "val": 7432306720}
+        ]}"#;
+        let mut server = mockito::Server::new_async().await;
+        let frame_mock = server
+            .mock(
+                "GET",
+                "/api/xbrl/frames/dei/EntityCommonStockSharesOutstanding/shares/CY2023Q4I.json",
+            )
+            .with_status(200)
+            .with_body(body)
+            .expect(2)
+            .create_async()
+            .await;
+        let config = EdgarConfig {
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let period = Period::Instantaneous(2023, 4);
+
+        let frame = EdgarParser::fetch_xbrl_frames_in_with_config(
+            Taxonomy::Dei,
+            "EntityCommonStockSharesOutstanding",
+            "shares",
+            period,
+            &config,
+        )
+        .await
+        .unwrap();
+        assert_eq!(frame["taxonomy"], "dei");
+        assert_eq!(frame["data"].as_array().unwrap().len(), 2);
+
+        let mut ciks = Vec::new();
+        let header = EdgarParser::fetch_xbrl_frames_streaming_in_with_config(
+            Taxonomy::Dei,
+            "EntityCommonStockSharesOutstanding",
+            "shares",
+            period,
+            |datum| ciks.push(datum.cik),
+            &config,
+        )
+        .await
+        .unwrap();
+        frame_mock.assert_async().await;
+
+        assert_eq!(header.ccp, "CY2023Q4I");
+        assert_eq!(ciks, vec![320193, 789019]);
+    }
+
+    #[tokio::test]
+    async fn test_all_filings_combines_supplemental_files() {
+        let mut server = mockito::Server::new_async().await;
+        let supplemental_mock = server
+            .mock("GET", "/submissions/CIK0000320193-submissions-001.json")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "accessionNumber": ["0000320193-05-000008", "0000320193-04-000003"],
+                    "filingDate": ["2005-01-21", "2004-12-03"],
+                    "form": ["10-Q", "10-K"]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser {
+            cik_str: Some(320193),
+            ticker: Some("AAPL".to_string()),
+            title: Some("Apple Inc.".to_string()),
+            submissions: Some(serde_json::json!({
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["0000320193-24-000006", "0000320193-23-000106"],
+                        "filingDate": ["2024-02-02", "2023-11-03"],
+                        "form": ["10-Q", "10-K"]
+                    },
+                    "files": [{
+                        "name": "CIK0000320193-submissions-001.json",
+                        "filingCount": 2,
+                        "filingFrom": "2004-12-03",
+                        "filingTo": "2005-01-21"
+                    }]
+                }
+            })),
+            company_facts: None,
+            leading_zero_cik: "0000320193".to_string(),
+            config: EdgarConfig {
+                data_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+        };
+
+        let filings = parser.all_filings().await.unwrap();
+        supplemental_mock.assert_async().await;
+
+        let accessions: Vec<&str> = filings
+            .iter()
+            .map(|f| f.accession_number.as_str())
+            .collect();
+        assert_eq!(
+            accessions,
+            vec![
+                "0000320193-04-000003",
+                "0000320193-05-000008",
+                "0000320193-23-000106",
+                "0000320193-24-000006",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_company_facts_typed() {
+        let mut server = mockito::Server::new_async().await;
+        let facts_mock = server
+            .mock("GET", "/api/xbrl/companyfacts/CIK0000320193.json")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "cik": 320193,
+                    "entityName": "Apple Inc.",
+                    "facts": {
+                        "us-gaap": {
+                            "EarningsPerShareBasic": {
+                                "label": "Earnings Per Share, Basic",
+                                "description": "The amount of net income or loss for the period per each share.",
+                                "units": {
+                                    "USD-per-shares": [
+                                        {"start": "2022-09-25", "end": "2023-09-30", "val": 6.16, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                                    ]
+                                }
+                            },
+                            "Revenues": {
+                                "label": "Revenues",
+                                "units": {
+                                    "USD": [
+                                        {"start": "2022-09-25", "end": "2023-09-30", "val": 383285000000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                                    ],
+                                    "EUR": [
+                                        {"start": "2022-09-25", "end": "2023-09-30", "val": 355000000000, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                                    ]
+                                }
+                            }
+                        }
+                    }
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser {
+            config: EdgarConfig {
+                data_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+            ..EdgarParser::from_cik_unchecked(320193)
+        };
+
+        let facts = parser.fetch_company_facts_typed().await.unwrap();
+        facts_mock.assert_async().await;
+
+        assert_eq!(facts.entity_name, "Apple Inc.");
+        let eps = facts.concept("us-gaap", "EarningsPerShareBasic").unwrap();
+        assert_eq!(eps.latest("USD-per-shares").unwrap().val, 6.16);
+        let revenues = facts.concept("us-gaap", "Revenues").unwrap();
+        assert_eq!(revenues.units.len(), 2);
+        assert_eq!(revenues.latest("USD").unwrap().val, 383285000000.0);
+        assert!(parser.company_facts.is_some());
+    }
+
+    #[test]
+    fn test_concept_series() {
+        let mut parser = EdgarParser::from_cik_unchecked(320193);
+        assert!(matches!(
+            parser.concept_series("us-gaap", "Revenues", "USD"),
+            Err(EDGARParserError::NotFound(_))
+        ));
+
+        parser.company_facts = Some(serde_json::json!({
+            "cik": 320193,
+            "entityName": "Apple Inc.",
+            "facts": {
+                "us-gaap": {
+                    "Revenues": {
+                        "label": "Revenues",
+                        "units": {
+                            "USD": [
+                                {"start": "2022-09-25", "end": "2023-09-30", "val": 383285000000.0, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"},
+                                {"start": "2020-09-27", "end": "2021-09-25", "val": 365817000000.0, "accn": "0000320193-21-000105", "fy": 2021, "fp": "FY", "form": "10-K", "filed": "2021-10-29"},
+                                {"start": "2021-09-26", "end": "2022-09-24", "val": 394328000000.0, "accn": "0000320193-22-000108", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2022-10-28"},
+                                {"start": "2021-09-26", "end": "2022-09-24", "val": 394330000000.0, "accn": "0000320193-23-000106", "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                            ]
+                        }
+                    }
+                }
+            }
+        }));
+
+        let series = parser.concept_series("us-gaap", "Revenues", "USD").unwrap();
+        let ends: Vec<&str> = series.iter().map(|point| point.end.as_str()).collect();
+        assert_eq!(ends, ["2021-09-25", "2022-09-24", "2023-09-30"]);
+        assert_eq!(series[1].val, 394330000000.0);
+        assert_eq!(series[1].accn, "0000320193-23-000106");
+
+        assert!(matches!(
+            parser.concept_series("us-gaap", "Revenues", "EUR"),
+            Err(EDGARParserError::NotFound(_))
+        ));
+        assert!(matches!(
+            parser.concept_series("us-gaap", "Assets", "USD"),
+            Err(EDGARParserError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_submissions_typed() {
+        let mut server = mockito::Server::new_async().await;
+        let submissions_mock = server
+            .mock("GET", "/submissions/CIK0000320193.json")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "cik": "320193",
+                    "name": "Apple Inc.",
+                    "sic": "3571",
+                    "sicDescription": "Electronic Computers",
+                    "tickers": ["AAPL"],
+                    "exchanges": ["Nasdaq"],
+                    "filings": {
+                        "recent": {
+                            "accessionNumber": ["0000320193-24-000006"],
+                            "filingDate": ["2024-02-02"],
+                            "form": ["10-Q"],
+                            "primaryDocument": ["aapl-20231230.htm"]
+                        },
+                        "files": [{
+                            "name": "CIK0000320193-submissions-001.json",
+                            "filingCount": 2,
+                            "filingFrom": "2004-12-03",
+                            "filingTo": "2005-01-21"
+                        }]
+                    }
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser {
+            config: EdgarConfig {
+                data_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+            ..EdgarParser::from_cik_unchecked(320193)
+        };
+
+        let submissions = parser.fetch_submissions_typed().await.unwrap();
+        submissions_mock.assert_async().await;
+
+        assert_eq!(submissions.name, "Apple Inc.");
+        assert_eq!(submissions.sic.as_deref(), Some("3571"));
+        assert_eq!(submissions.exchanges, vec!["Nasdaq".to_string()]);
+        assert_eq!(
+            submissions.filings.recent.accession_number,
+            vec!["0000320193-24-000006".to_string()]
+        );
+        assert_eq!(submissions.filings.files.len(), 1);
+        assert!(parser.submissions.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_10k_text() {
+        let mut server = mockito::Server::new_async().await;
+        let document_mock = server
+            .mock("GET", "/320193/000032019323000106/aapl-20230930.htm")
+            .with_status(200)
+            .with_body(
+                "<html><body><p>Item 7.&nbsp;Management&#8217;s Discussion</p></body></html>",
+            )
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser {
+            cik_str: Some(320193),
+            ticker: Some("AAPL".to_string()),
+            title: Some("Apple Inc.".to_string()),
+            submissions: Some(serde_json::json!({
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["0000320193-24-000006", "0000320193-23-000106"],
+                        "filingDate": ["2024-02-02", "2023-11-03"],
+                        "form": ["10-Q", "10-K"],
+                        "primaryDocument": ["aapl-20231230.htm", "aapl-20230930.htm"]
+                    }
+                }
+            })),
+            company_facts: None,
+            leading_zero_cik: "0000320193".to_string(),
+            config: EdgarConfig {
+                archives_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+        };
+
+        let text = parser.fetch_latest_10k_text().await.unwrap();
+        document_mock.assert_async().await;
+        assert_eq!(text, "Item 7. Management’s Discussion");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_10k_text_without_primary_document() {
+        let mut server = mockito::Server::new_async().await;
+        let index_mock = server
+            .mock("GET", "/320193/000091205700045397/index.json")
+            .with_status(200)
+            .with_body(
+                r#"{"directory": {"item": [
+                    {"name": "0000912057-00-045397-index.htm"},
+                    {"name": "0000912057-00-045397.txt"},
+                    {"name": "a2030011z10-k.txt"}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        let document_mock = server
+            .mock("GET", "/320193/000091205700045397/a2030011z10-k.txt")
+            .with_status(200)
+            .with_body("ANNUAL REPORT PURSUANT TO SECTION 13")
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser {
+            submissions: Some(serde_json::json!({
+                "cik": "320193",
+                "name": "Apple Computer Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["0000912057-00-045397"],
+                        "filingDate": ["2000-12-14"],
+                        "form": ["10-K"],
+                        "primaryDocument": [""]
+                    }
+                }
+            })),
+            config: EdgarConfig {
+                archives_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+            ..EdgarParser::from_cik_unchecked(320193)
+        };
+
+        let text = parser.fetch_latest_10k_text().await.unwrap();
+        index_mock.assert_async().await;
+        document_mock.assert_async().await;
+        assert_eq!(text, "ANNUAL REPORT PURSUANT TO SECTION 13");
+    }
+
+    #[tokio::test]
+    async fn test_insider_transactions() {
+        let form_4 = |owner: &str, code: &str, shares: u32| {
+            format!(
+                r#"<ownershipDocument>
+                    <reportingOwner>
+                        <reportingOwnerId><rptOwnerCik>0001214128</rptOwnerCik><rptOwnerName>{}</rptOwnerName></reportingOwnerId>
+                    </reportingOwner>
+                    <nonDerivativeTable>
+                        <nonDerivativeTransaction>
+                            <securityTitle><value>Common Stock</value></securityTitle>
+                            <transactionDate><value>2024-04-01</value></transactionDate>
+                            <transactionCoding><transactionCode>{}</transactionCode></transactionCoding>
+                            <transactionAmounts>
+                                <transactionShares><value>{}</value></transactionShares>
+                                <transactionAcquiredDisposedCode><value>D</value></transactionAcquiredDisposedCode>
+                            </transactionAmounts>
+                        </nonDerivativeTransaction>
+                    </nonDerivativeTable>
+                </ownershipDocument>"#,
+                owner, code, shares
+            )
+        };
+
+        let mut server = mockito::Server::new_async().await;
+        let newer = server
+            .mock("GET", "/320193/000032019424000070/form4-b.xml")
+            .with_status(200)
+            .with_body(form_4("COOK TIMOTHY D", "S", 1000))
+            .create_async()
+            .await;
+        let older = server
+            .mock("GET", "/320193/000032019424000060/form4-a.xml")
+            .with_status(200)
+            .with_body(form_4("LEVINSON ARTHUR D", "M", 500))
+            .create_async()
+            .await;
+        // The older filing has no primaryDocument, so its XML is found through index.json
+        // rather than the text renderings beside it.
+        let older_index = server
+            .mock("GET", "/320193/000032019424000060/index.json")
+            .with_status(200)
+            .with_body(
+                r#"{"directory": {"item": [
+                    {"name": "0000320194-24-000060-index-headers.html"},
+                    {"name": "0000320194-24-000060-index.html"},
+                    {"name": "0000320194-24-000060.txt"},
+                    {"name": "form4-a.xml"}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        let too_old = server
+            .mock("GET", "/320193/000032019423000010/form4-old.xml")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser {
+            submissions: Some(serde_json::json!({
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": [
+                            "0000320194-24-000070",
+                            "0000320193-24-000065",
+                            "0000320194-24-000060",
+                            "0000320194-23-000010"
+                        ],
+                        "filingDate": ["2024-04-03", "2024-04-02", "2024-04-01", "2023-01-05"],
+                        "form": ["4", "10-Q", "4", "4"],
+                        "primaryDocument": [
+                            "xslF345X05/form4-b.xml",
+                            "aapl-20240330.htm",
+                            "",
+                            "xslF345X05/form4-old.xml"
+                        ]
+                    }
+                }
+            })),
+            config: EdgarConfig {
+                archives_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+            ..EdgarParser::from_cik_unchecked(320193)
+        };
+
+        let transactions = parser
+            .insider_transactions(NaiveDate::from_ymd_opt(2024, 1, 1))
+            .await
+            .unwrap();
+        newer.assert_async().await;
+        older.assert_async().await;
+        older_index.assert_async().await;
+        too_old.assert_async().await;
+
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].owner_name, "COOK TIMOTHY D");
+        assert_eq!(transactions[0].code, "S");
+        assert_eq!(transactions[0].shares, 1000.0);
+        assert_eq!(transactions[1].owner_name, "LEVINSON ARTHUR D");
+        assert_eq!(transactions[1].code, "M");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_xbrl_instance() {
+        let mut server = mockito::Server::new_async().await;
+        let index_mock = server
+            .mock("GET", "/320193/000032019323000106/index.json")
+            .with_status(200)
+            .with_body(crate::filing_index::tests::INLINE_INDEX_FIXTURE)
+            .create_async()
+            .await;
+        let instance_mock = server
+            .mock("GET", "/320193/000032019323000106/aapl-20230930_htm.xml")
+            .with_status(200)
+            .with_body("<xbrl></xbrl>")
+            .create_async()
+            .await;
+
+        let parser = EdgarParser {
+            cik_str: Some(320193),
+            ticker: Some("AAPL".to_string()),
+            title: Some("Apple Inc.".to_string()),
+            submissions: None,
+            company_facts: None,
+            leading_zero_cik: "0000320193".to_string(),
+            config: EdgarConfig {
+                archives_base_url: server.url(),
+                ..EdgarConfig::for_tests()
+            },
+        };
+
+        let instance = parser
+            .fetch_xbrl_instance("0000320193-23-000106")
+            .await
+            .unwrap();
+        index_mock.assert_async().await;
+        instance_mock.assert_async().await;
+        assert_eq!(instance, "<xbrl></xbrl>");
+    }
+
+    #[tokio::test]
+    async fn test_stream_quarterly_index_filters_form() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let index_mock = server
+            .mock("GET", "/2024/QTR1/master.idx")
+            .with_status(200)
+            .with_body(
+                "Description:           Master Index of EDGAR Dissemination Feed\n\
+                 Last Data Received:    March 31, 2024\n\
+                 \n\
+                 CIK|Company Name|Form Type|Date Filed|Filename\n\
+                 --------------------------------------------------------------------------------\n\
+                 320193|Apple Inc.|8-K|2024-02-01|edgar/data/320193/0000320193-24-000006.txt\n\
+                 320193|Apple Inc.|10-Q|2024-02-02|edgar/data/320193/0000320193-24-000006.txt\n\
+                 789019|MICROSOFT CORP|8-K|2024-01-30|edgar/data/789019/0001193125-24-017503.txt\n\
+                 789019|MICROSOFT CORP|4|2024-03-14|edgar/data/789019/0001062993-24-006137.txt",
+            )
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            full_index_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let entries: Vec<IndexEntry> = EdgarParser::stream_quarterly_index_with_config(
+            2024,
+            1,
+            Some(FilingTypeOption::_8K),
+            &config,
+        )
+        .map(|entry| entry.unwrap())
+        .collect()
+        .await;
+
+        index_mock.assert_async().await;
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.form_type == "8-K"));
+        assert_eq!(entries[0].cik, 320193);
+        assert_eq!(entries[1].company_name, "MICROSOFT CORP");
+
+        let config = EdgarConfig {
+            full_index_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        }
+        .max_response_bytes(128);
+        let results: Vec<Result<IndexEntry, EDGARParserError>> =
+            EdgarParser::stream_quarterly_index_with_config(2024, 1, None, &config)
+                .collect()
+                .await;
+        assert!(matches!(
+            results.last(),
+            Some(Err(EDGARParserError::ResponseTooLarge { limit: 128, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_for_ticker() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/company_tickers_exchange.json")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "fields": ["cik", "name", "ticker", "exchange"],
+                    "data": [
+                        [320193, "Apple Inc.", "AAPL", "Nasdaq"],
+                        [1067983, "BERKSHIRE HATHAWAY INC", "BRK-B", "NYSE"],
+                        [1000045, "NICHOLAS FINANCIAL INC", "NICK", null]
+                    ]
+                }"#,
+            )
+            .expect(4)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_exchange_url: format!("{}/company_tickers_exchange.json", server.url()),
+            ..EdgarConfig::for_tests()
+        };
+
+        let exchange = |ticker: &'static str| {
+            let config = config.clone();
+            async move {
+                EdgarParser::exchange_for_ticker_with_config(ticker, &config)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert_eq!(exchange("AAPL").await, Some("Nasdaq".to_string()));
+        assert_eq!(exchange("brk-b").await, Some("NYSE".to_string()));
+        assert_eq!(exchange("NICK").await, None);
+        assert_eq!(exchange("ZZZZ").await, None);
+    }
+
+    #[test]
+    fn test_from_cik_unchecked() {
+        // Synchronous and without a runtime, so the constructor cannot await any request.
+        let parser = EdgarParser::from_cik_unchecked(320193);
+
+        assert_eq!(parser.cik_str, Some(320193));
+        assert_eq!(parser.leading_zero_cik, "0000320193");
+        assert_eq!(parser.ticker, None);
+        assert!(parser.submissions.is_none());
+        assert!(parser.company_facts.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_download_filing_layouts() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/320193/000032019323000106/aapl-20230930.htm")
+            .with_status(200)
+            .with_body("<html>10-K</html>")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let mut parser = EdgarParser::from_cik_unchecked(320193);
+        parser.config = EdgarConfig {
+            archives_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let filing = Filing {
+            accession_number: "0000320193-23-000106".to_string(),
+            primary_document: "aapl-20230930.htm".to_string(),
+            ..Default::default()
+        };
+        let dir = std::env::temp_dir().join(format!("agora_download_{}", std::process::id()));
+
+        let cases = [
+            (DownloadLayout::Flat, dir.join("aapl-20230930.htm")),
+            (
+                DownloadLayout::NestedByCik,
+                dir.join("320193").join("aapl-20230930.htm"),
+            ),
+            (
+                DownloadLayout::NestedByAccession,
+                dir.join("320193")
+                    .join("0000320193-23-000106")
+                    .join("aapl-20230930.htm"),
+            ),
+        ];
+        for (layout, expected) in cases {
+            let path = parser.download_filing(&filing, &dir, layout).await.unwrap();
+            assert_eq!(path, expected);
+            assert_eq!(fs::read_to_string(&path).unwrap(), "<html>10-K</html>");
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_facts_index_resumes() {
+        let mut server = mockito::Server::new_async().await;
+        for cik in ["0000320193", "0000789019"] {
+            server
+                .mock(
+                    "GET",
+                    format!("/api/xbrl/companyfacts/CIK{}.json", cik).as_str(),
+                )
+                .with_status(200)
+                .with_body(format!(
+                    r#"{{"cik": {}, "entityName": "TEST", "facts": {{}}}}"#,
+                    cik
+                ))
+                .expect(1)
+                .create_async()
+                .await;
+        }
+        server
+            .mock("GET", "/api/xbrl/companyfacts/CIK0000000001.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let dir = std::env::temp_dir().join(format!("agora_facts_index_{}", std::process::id()));
+
+        let first =
+            EdgarParser::download_facts_index_with_config(&[320193, 789019, 1], &dir, &config)
+                .await
+                .unwrap();
+        assert_eq!(first.downloaded, vec![320193, 789019]);
+        assert!(first.skipped.is_empty());
+        assert_eq!(first.failed.len(), 1);
+        assert_eq!(first.failed[0].0, 1);
+        assert!(dir.join("CIK0000320193.json").exists());
+
+        let second =
+            EdgarParser::download_facts_index_with_config(&[320193, 789019], &dir, &config)
+                .await
+                .unwrap();
+        assert!(second.downloaded.is_empty());
+        assert_eq!(second.skipped, vec![320193, 789019]);
+        assert!(second.is_complete());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_resolve_ticker_with_history() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/company_tickers.json")
+            .with_status(200)
+            .with_body(
+                r#"{"0": {"cik_str": 1326801, "ticker": "META", "title": "Meta Platforms, Inc."}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/submissions/CIK0001000001.json")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "cik": "1000001",
+                    "name": "NEXT BRIDGE HOLDINGS",
+                    "tickers": ["NBH"],
+                    "formerNames": [{"name": "METAVERSE HOLDINGS", "from": "2019-01-01T00:00:00.000Z", "to": "2022-06-30T00:00:00.000Z"}],
+                    "filings": {
+                        "recent": {
+                            "accessionNumber": ["0001000001-23-000004", "0001000001-22-000002"],
+                            "filingDate": ["2023-03-01", "2022-03-01"],
+                            "form": ["10-K", "10-K"],
+                            "primaryDocument": ["nbh-20221231.htm", "meta-20211231.htm"]
+                        }
+                    }
+                }"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/submissions/CIK0001000002.json")
+            .with_status(200)
+            .with_body(r#"{"cik": "1000002", "name": "UNRELATED CORP", "tickers": ["UNRL"], "formerNames": [{"name": "UNRELATED INC", "from": "2001-01-01T00:00:00.000Z", "to": "2010-01-01T00:00:00.000Z"}]}"#)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/company_tickers.json", server.url()),
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let holders = EdgarParser::resolve_ticker_with_history_with_config(
+            "META",
+            &[1326801, 1000001, 1000002],
+            &config,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            holders,
+            vec![
+                (1326801, "Meta Platforms, Inc.".to_string()),
+                (1000001, "METAVERSE HOLDINGS".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_watchlist() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/company_tickers.json")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "0": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+                    "1": {"cik_str": 789019, "ticker": "MSFT", "title": "MICROSOFT CORP"},
+                    "2": {"cik_str": 1652044, "ticker": "GOOGL", "title": "Alphabet Inc."}
+                }"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/company_tickers.json", server.url()),
+            ..EdgarConfig::for_tests()
+        };
+        let report = EdgarParser::validate_watchlist_with_config(
+            &["aapl", "APPL", "MSFT", "ZZZZZZZZ"],
+            &config,
+        )
+        .await
+        .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            report.resolved,
+            vec![("AAPL".to_string(), 320193), ("MSFT".to_string(), 789019)]
+        );
+        assert_eq!(report.unknown, vec!["APPL", "ZZZZZZZZ"]);
+        assert_eq!(report.suggestions.len(), 1);
+        assert_eq!(report.suggestions["APPL"], vec!["Apple Inc."]);
+        assert!(!report.is_clean());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_current_filings() {
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let feed_mock = server
+            .mock("GET", "/cgi-bin/browse-edgar")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("action".into(), "getcurrent".into()),
+                Matcher::UrlEncoded("type".into(), "8-K".into()),
+                Matcher::UrlEncoded("owner".into(), "include".into()),
+                Matcher::UrlEncoded("count".into(), "40".into()),
+                Matcher::UrlEncoded("output".into(), "atom".into()),
+            ]))
+            .with_status(200)
+            .with_body(crate::atom::tests::CURRENT_EVENTS_FIXTURE)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            browse_edgar_url: format!("{}/cgi-bin/browse-edgar", server.url()),
+            ..EdgarConfig::for_tests()
+        };
+
+        let entries = EdgarParser::fetch_current_filings_with_config(
+            Some(FilingTypeOption::_8K),
+            40,
+            &config,
+        )
+        .await
+        .unwrap();
+
+        feed_mock.assert_async().await;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].form, "8-K");
+        assert_eq!(entries[1].accession_number, "0001193125-24-017503");
+    }
+
+    #[tokio::test]
+    async fn test_cik_exists() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("HEAD", "/submissions/CIK0000320193.json")
+            .with_status(200)
+            .create_async()
+            .await;
+        server
+            .mock("HEAD", "/submissions/CIK0000000001.json")
+            .with_status(404)
+            .create_async()
+            .await;
+        server
+            .mock("HEAD", "/submissions/CIK0000000002.json")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+
+        assert!(
+            EdgarParser::cik_exists_with_config(320193, &config)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !EdgarParser::cik_exists_with_config(1, &config)
+                .await
+                .unwrap()
+        );
+        assert!(matches!(
+            EdgarParser::cik_exists_with_config(2, &config).await,
+            Err(EDGARParserError::HttpError(_))
+        ));
+    }
+
     #[test]
     fn test_deserialize_edgar_parser_with_padding() {
         let json = r#"
@@ -212,13 +2491,106 @@ mod tests {
         assert_eq!(parsed.leading_zero_cik, "0001045810");
     }
 
+    #[tokio::test]
+    async fn test_fetch_frames_joined() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/xbrl/frames/us-gaap/Revenues/USD/CY2023.json")
+            .with_status(200)
+            .with_body(
+                r#"{"taxonomy": "us-gaap", "tag": "Revenues", "ccp": "CY2023", "uom": "USD", "pts": 2, "data": [
+                    {"accn": "0000000001-24-000001", "cik": 1, "entityName": "ALPHA", "loc": "US-NY", "start": "2023-01-01", "end": "2023-12-31", "val": 100},
+                    {"accn": "0000000002-24-000001", "cik": 2, "entityName": "BETA", "loc": "US-CA", "start": "2023-01-01", "end": "2023-12-31", "val": 200}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/xbrl/frames/us-gaap/NetIncomeLoss/USD/CY2023.json")
+            .with_status(200)
+            .with_body(
+                r#"{"taxonomy": "us-gaap", "tag": "NetIncomeLoss", "ccp": "CY2023", "uom": "USD", "pts": 2, "data": [
+                    {"accn": "0000000002-24-000001", "cik": 2, "entityName": "BETA", "loc": "US-CA", "start": "2023-01-01", "end": "2023-12-31", "val": 20},
+                    {"accn": "0000000003-24-000001", "cik": 3, "entityName": "GAMMA", "loc": "US-TX", "start": "2023-01-01", "end": "2023-12-31", "val": -5}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let rows = EdgarParser::fetch_frames_joined_with_config(
+            Taxonomy::UsGaap,
+            &[("Revenues", "USD"), ("NetIncomeLoss", "USD")],
+            Period::Annual(2023),
+            &config,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[&1], HashMap::from([("Revenues".to_string(), 100.0)]));
+        assert_eq!(
+            rows[&2],
+            HashMap::from([
+                ("Revenues".to_string(), 200.0),
+                ("NetIncomeLoss".to_string(), 20.0),
+            ])
+        );
+        assert_eq!(
+            rows[&3],
+            HashMap::from([("NetIncomeLoss".to_string(), -5.0)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_xbrl_frames_for_ciks() {
+        let mut server = mockito::Server::new_async().await;
+        let frame_mock = server
+            .mock("GET", "/api/xbrl/frames/us-gaap/Revenues/USD/CY2023.json")
+            .with_status(200)
+            .with_body(
+                r#"{"taxonomy": "us-gaap", "tag": "Revenues", "ccp": "CY2023", "uom": "USD", "pts": 3, "data": [
+                    {"accn": "0000000001-24-000001", "cik": 1, "entityName": "ALPHA", "loc": "US-NY", "start": "2023-01-01", "end": "2023-12-31", "val": 100},
+                    {"accn": "0000000002-24-000001", "cik": 2, "entityName": "BETA", "loc": "US-CA", "start": "2023-01-01", "end": "2023-12-31", "val": 200},
+                    {"accn": "0000000003-24-000001", "cik": 3, "entityName": "GAMMA", "loc": "US-TX", "start": "2023-01-01", "end": "2023-12-31", "val": 300}
+                ]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = EdgarConfig {
+            data_base_url: server.url(),
+            ..EdgarConfig::for_tests()
+        };
+        let data = EdgarParser::fetch_xbrl_frames_for_ciks_with_config(
+            Taxonomy::UsGaap,
+            "Revenues",
+            "USD",
+            Period::Annual(2023),
+            &[3, 1, 99],
+            &config,
+        )
+        .await
+        .unwrap();
+        frame_mock.assert_async().await;
+
+        let mut ciks: Vec<u32> = data.keys().copied().collect();
+        ciks.sort();
+        assert_eq!(ciks, vec![1, 3]);
+        assert_eq!(data[&3].entity_name, "GAMMA");
+        assert_eq!(data[&1].val, 100.0);
+    }
+
     #[tokio::test]
     async fn test_fetch_xbrl_frames_success() {
         let fact: &'static str = "Assets";
         let unit: &'static str = "USD";
-        let year: u16 = 2020;
-        let quarter: u8 = 1;
-        let result = EdgarParser::fetch_xbrl_frames(fact, unit, &year, &quarter);
+        let period = Period::Instantaneous(2020, 1);
+        let result = EdgarParser::fetch_xbrl_frames(fact, unit, period);
         assert!(result.await.is_ok());
 
         // let json = result.unwrap();
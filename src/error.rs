@@ -9,12 +9,38 @@ pub enum EDGARParserError {
     #[error("Failed to parse JSON: {0}")]
     JSONParseError(#[from] serde_json::Error),
 
+    #[error("Failed to parse JSON: {source}; response body began with: {body_snippet}")]
+    JSONParseErrorWithBody {
+        source: serde_json::Error,
+        body_snippet: String,
+    },
+
+    #[error(
+        "No Tokio runtime is running; call EDGAR requests from within a Tokio runtime, e.g. under #[tokio::main] or Runtime::block_on"
+    )]
+    NoRuntime,
+
+    #[error("Request to {0} timed out")]
+    Timeout(String),
+
+    #[error("Response from {url} exceeded the limit of {limit} bytes")]
+    ResponseTooLarge { url: String, limit: u64 },
+
+    #[error("Response from {0} had an empty body")]
+    EmptyBody(String),
+
     #[error("Requested resource not found: {0}")]
     NotFound(String),
 
     #[error("Unauthorized access: {0}")]
     Unauthorized(String),
 
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse XML: {0}")]
+    XMLParseError(#[from] serde_xml_rs::Error),
+
     #[error("Received invalid response: {0}")]
     InvalidResponse(String),
 
@@ -27,8 +53,34 @@ pub enum EDGARParserError {
     #[error("Invalid date format supplied {0}, it should be yyyymmdd.")]
     InvalidDateFormat(String),
 
+    #[error("Invalid count {0}, it should be between 1 and 100.")]
+    InvalidCount(u32),
+
     #[error("EDGAR Owner Type not found.")]
     OwnerTypeNotFound(),
+
+    #[error("Invalid User-Agent: {0}")]
+    InvalidUserAgent(String),
+
+    #[error(
+        "No User-Agent configured; set one with EdgarConfig::set_user_agent or the USER_AGENT environment variable"
+    )]
+    MissingUserAgent,
+
+    #[error("EDGAR Action not found.")]
+    ActionNotFound(),
+
+    #[error("Incompatible query parameters: {0}")]
+    IncompatibleQuery(String),
+
+    #[error("Invalid frame period {0}, expected CY####, CY####Q# or CY####Q#I.")]
+    InvalidFramePeriod(String),
+
+    #[error("Unexpected response schema: {0}")]
+    UnexpectedSchema(String),
+
+    #[error("Invalid client configuration: {0}")]
+    InvalidConfig(String),
 }
 
 impl From<ParseError> for EDGARParserError {
@@ -0,0 +1,216 @@
+use serde::Deserialize;
+
+// Suffixes of the XBRL linkbase files that accompany an instance document.
+const LINKBASE_SUFFIXES: [&str; 4] = ["_cal.xml", "_def.xml", "_lab.xml", "_pre.xml"];
+
+// Suffix of the instance document the SEC extracts from an inline-XBRL primary document.
+const EXTRACTED_INSTANCE_SUFFIX: &str = "_htm.xml";
+
+/// Represents the `index.json` directory listing of a filing folder in the EDGAR Archives.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct FilingIndex {
+    pub directory: FilingDirectory,
+}
+
+/// Represents the directory block of a filing index.
+///
+/// # Fields
+/// - `name`: Path of the folder, e.g. `/Archives/edgar/data/320193/000032019323000106`.
+/// - `item`: The files in the folder.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct FilingDirectory {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub item: Vec<FilingIndexItem>,
+}
+
+/// Represents one file of a filing folder.
+///
+/// # Fields
+/// - `name`: File name, e.g. `aapl-20230930_htm.xml`.
+/// - `item_type`: The SEC's icon type for the file, e.g. `text.gif`.
+/// - `size`: File size in bytes as reported by the SEC (empty for folders).
+/// - `last_modified`: Last modification timestamp, formatted `YYYY-MM-DD HH:MM:SS`.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct FilingIndexItem {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub item_type: String,
+    #[serde(default)]
+    pub size: String,
+    #[serde(rename = "last-modified", default)]
+    pub last_modified: String,
+}
+
+impl FilingIndex {
+    /// Returns the name of the filing's XBRL instance document. The instance the SEC extracts
+    /// from an inline-XBRL filing (`*_htm.xml`) is preferred; otherwise the first XML file that
+    /// is neither a linkbase (`_cal`, `_def`, `_lab`, `_pre`) nor `FilingSummary.xml` is used.
+    pub fn xbrl_instance_name(&self) -> Option<&str> {
+        let names = || self.directory.item.iter().map(|i| i.name.as_str());
+
+        names()
+            .find(|name| name.ends_with(EXTRACTED_INSTANCE_SUFFIX))
+            .or_else(|| names().find(|name| is_traditional_instance(name)))
+    }
+
+    /// Returns the name of the document to treat as the filing's primary document, for older
+    /// filings whose submissions entry has no `primaryDocument`: the first HTML or text
+    /// document in the folder, or else the complete submission text file.
+    pub fn primary_document_name(&self) -> Option<&str> {
+        let names = || self.directory.item.iter().map(|i| i.name.as_str());
+
+        names()
+            .find(|name| is_filing_document(name))
+            .or_else(|| names().find(|name| is_complete_submission(name)))
+    }
+
+    /// Returns the name of the XML document of an ownership filing (Forms 3, 4 and 5), for
+    /// filings whose submissions entry has no `primaryDocument`: the first XML file in the
+    /// folder other than `FilingSummary.xml`. The HTML and text files alongside it are
+    /// renderings that cannot be parsed as an ownership document.
+    pub fn ownership_document_name(&self) -> Option<&str> {
+        self.directory
+            .item
+            .iter()
+            .map(|i| i.name.as_str())
+            .find(|name| {
+                name.to_ascii_lowercase().ends_with(".xml") && *name != "FilingSummary.xml"
+            })
+    }
+}
+
+/// Returns `true` for the complete submission text file, named after the accession number,
+/// e.g. `0000320193-23-000106.txt`.
+fn is_complete_submission(name: &str) -> bool {
+    name.strip_suffix(".txt").is_some_and(|stem| {
+        let parts: Vec<&str> = stem.split('-').collect();
+        parts.len() == 3
+            && parts
+                .iter()
+                .zip([10, 2, 6])
+                .all(|(part, len)| part.len() == len && part.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+/// Returns `true` for an HTML or text document of the filing, excluding the index pages the
+/// SEC generates for the folder and the complete submission.
+fn is_filing_document(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    let is_document = [".htm", ".html", ".txt"]
+        .iter()
+        .any(|extension| lower.ends_with(extension));
+
+    is_document && !lower.contains("-index") && !is_complete_submission(name)
+}
+
+/// Returns `true` for an XML file that is not a linkbase or the filing summary.
+fn is_traditional_instance(name: &str) -> bool {
+    name.ends_with(".xml")
+        && !name.eq_ignore_ascii_case("FilingSummary.xml")
+        && !LINKBASE_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const INLINE_INDEX_FIXTURE: &str = r#"{
+        "directory": {
+            "item": [
+                {"last-modified": "2023-11-02 18:08:27", "name": "0000320193-23-000106-index-headers.html", "type": "text.gif", "size": ""},
+                {"last-modified": "2023-11-02 18:08:27", "name": "0000320193-23-000106.txt", "type": "text.gif", "size": ""},
+                {"last-modified": "2023-11-02 18:08:27", "name": "FilingSummary.xml", "type": "text.gif", "size": "66433"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930.htm", "type": "text.gif", "size": "1599108"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930.xsd", "type": "text.gif", "size": "52117"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930_cal.xml", "type": "text.gif", "size": "88129"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930_def.xml", "type": "text.gif", "size": "256744"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930_htm.xml", "type": "text.gif", "size": "749863"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930_lab.xml", "type": "text.gif", "size": "586744"},
+                {"last-modified": "2023-11-02 18:08:27", "name": "aapl-20230930_pre.xml", "type": "text.gif", "size": "379520"}
+            ],
+            "name": "/Archives/edgar/data/320193/000032019323000106",
+            "parent-dir": "/Archives/edgar/data/320193"
+        }
+    }"#;
+
+    #[test]
+    fn test_xbrl_instance_prefers_extracted_inline_instance() {
+        let index: FilingIndex = serde_json::from_str(INLINE_INDEX_FIXTURE).unwrap();
+        assert_eq!(index.xbrl_instance_name(), Some("aapl-20230930_htm.xml"));
+    }
+
+    #[test]
+    fn test_xbrl_instance_traditional() {
+        let index: FilingIndex = serde_json::from_str(
+            r#"{"directory": {"item": [
+                {"name": "FilingSummary.xml"},
+                {"name": "aapl-20100925_cal.xml"},
+                {"name": "aapl-20100925.xml"},
+                {"name": "aapl-20100925.xsd"}
+            ]}}"#,
+        )
+        .unwrap();
+        assert_eq!(index.xbrl_instance_name(), Some("aapl-20100925.xml"));
+    }
+
+    #[test]
+    fn test_primary_document_name() {
+        let index: FilingIndex = serde_json::from_str(INLINE_INDEX_FIXTURE).unwrap();
+        assert_eq!(index.primary_document_name(), Some("aapl-20230930.htm"));
+
+        let index: FilingIndex = serde_json::from_str(
+            r#"{"directory": {"item": [
+                {"name": "0000912057-00-045397-index.htm"},
+                {"name": "0000912057-00-045397.txt"}
+            ]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            index.primary_document_name(),
+            Some("0000912057-00-045397.txt")
+        );
+
+        let index: FilingIndex =
+            serde_json::from_str(r#"{"directory": {"item": [{"name": "R1.xml"}]}}"#).unwrap();
+        assert_eq!(index.primary_document_name(), None);
+    }
+
+    #[test]
+    fn test_ownership_document_name() {
+        let index: FilingIndex = serde_json::from_str(
+            r#"{"directory": {"item": [
+                {"name": "0001127602-24-012345-index-headers.html"},
+                {"name": "0001127602-24-012345-index.html"},
+                {"name": "0001127602-24-012345.txt"},
+                {"name": "wf-form4_171234567890123.xml"},
+                {"name": "xslF345X05", "type": "folder.gif"}
+            ]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            index.ownership_document_name(),
+            Some("wf-form4_171234567890123.xml")
+        );
+
+        let index: FilingIndex = serde_json::from_str(
+            r#"{"directory": {"item": [{"name": "FilingSummary.xml"}, {"name": "form4.xml"}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(index.ownership_document_name(), Some("form4.xml"));
+
+        let index: FilingIndex =
+            serde_json::from_str(r#"{"directory": {"item": [{"name": "form4.htm"}]}}"#).unwrap();
+        assert_eq!(index.ownership_document_name(), None);
+    }
+
+    #[test]
+    fn test_xbrl_instance_missing() {
+        let index: FilingIndex =
+            serde_json::from_str(r#"{"directory": {"item": [{"name": "d123.htm"}]}}"#).unwrap();
+        assert_eq!(index.xbrl_instance_name(), None);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::EDGARParserError;
+use phf::{Map, phf_map};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[allow(missing_docs)]
+pub enum Action {
+    /// "getcompany" lists the filings of a single company, identified by its CIK.
+    #[default]
+    GetCompany,
+    /// "getcurrent" lists the latest filings across all companies, without a CIK.
+    GetCurrent,
+}
+
+// Static map for string -> enum conversion
+static ACTION_MAP: Map<&'static str, Action> = phf_map! {
+    "GETCOMPANY" => Action::GetCompany,
+    "GETCURRENT" => Action::GetCurrent,
+};
+
+impl FromStr for Action {
+    type Err = EDGARParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ACTION_MAP
+            .get(&s.to_uppercase() as &str)
+            .copied()
+            .ok_or(EDGARParserError::ActionNotFound())
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Action::GetCompany => "getcompany".to_string(),
+            Action::GetCurrent => "getcurrent".to_string(),
+        };
+        write!(f, "{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str_case_insensitive() {
+        let action: Action = Action::from_str("getCurrent").unwrap();
+        assert_eq!(action, Action::GetCurrent);
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        let result: Result<Action, EDGARParserError> = Action::from_str("getnothing");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_display_trait() {
+        assert_eq!(Action::GetCompany.to_string(), "getcompany");
+        assert_eq!(Action::GetCurrent.to_string(), "getcurrent");
+    }
+}
@@ -0,0 +1,56 @@
+use crate::filing_type_builder::filing::FilingTypeOption;
+
+/// Groups of related form types that can be queried together.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum FilingCategory {
+    /// Annual and quarterly reports (10-K, 10-Q, 20-F, 40-F).
+    PeriodicReports,
+    /// Reports of material events (8-K, 6-K).
+    CurrentReports,
+    /// Insider ownership filings (Forms 3, 4 and 5).
+    InsiderTransactions,
+    /// Securities registration statements (S-1, S-3, S-4, S-8, F-1, F-3, F-4).
+    Registrations,
+}
+
+impl FilingCategory {
+    /// Returns the form types that make up the category.
+    pub fn members(&self) -> &'static [FilingTypeOption] {
+        match self {
+            FilingCategory::PeriodicReports => &[
+                FilingTypeOption::_10K,
+                FilingTypeOption::_10Q,
+                FilingTypeOption::_20F,
+                FilingTypeOption::_40F,
+            ],
+            FilingCategory::CurrentReports => &[FilingTypeOption::_8K, FilingTypeOption::_6K],
+            FilingCategory::InsiderTransactions => &[
+                FilingTypeOption::_3,
+                FilingTypeOption::_4,
+                FilingTypeOption::_5,
+            ],
+            FilingCategory::Registrations => &[
+                FilingTypeOption::S1,
+                FilingTypeOption::S3,
+                FilingTypeOption::S4,
+                FilingTypeOption::S8,
+                FilingTypeOption::F1,
+                FilingTypeOption::F3,
+                FilingTypeOption::F4,
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_periodic_reports_members() {
+        let members = FilingCategory::PeriodicReports.members();
+        assert!(members.contains(&FilingTypeOption::_10K));
+        assert!(members.contains(&FilingTypeOption::_10Q));
+        assert!(!members.contains(&FilingTypeOption::_8K));
+    }
+}
@@ -3,14 +3,15 @@ use phf::{Map, phf_map};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-#[allow(missing_docs)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[allow(missing_docs, clippy::upper_case_acronyms)]
 pub enum FilingTypeOption {
     _1A,
     _1E,
     _1K,
     _1N,
     _1SA,
+    #[default]
     _1U,
     _1Z,
     _10,
@@ -312,12 +313,6 @@ impl FromStr for FilingTypeOption {
     }
 }
 
-impl Default for FilingTypeOption {
-    fn default() -> Self {
-        FilingTypeOption::_1U
-    }
-}
-
 impl fmt::Display for FilingTypeOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
@@ -538,4 +533,28 @@ mod tests {
         let s: Result<String, EDGARParserError> = validate_filing_type_string("WRONG-FORM");
         assert!(s.is_err());
     }
+
+    #[test]
+    fn test_sort_is_stable_declaration_order() {
+        let mut forms: Vec<FilingTypeOption> = vec![
+            FilingTypeOption::S1,
+            FilingTypeOption::_8K,
+            FilingTypeOption::_10Q,
+            FilingTypeOption::_10K,
+            FilingTypeOption::_4,
+            FilingTypeOption::_10K,
+        ];
+        forms.sort();
+        assert_eq!(
+            forms,
+            vec![
+                FilingTypeOption::_10K,
+                FilingTypeOption::_10K,
+                FilingTypeOption::_10Q,
+                FilingTypeOption::_4,
+                FilingTypeOption::_8K,
+                FilingTypeOption::S1,
+            ]
+        );
+    }
 }
@@ -1,97 +1,356 @@
-use crate::api::fetch_http_body;
+use crate::api::fetch_http_body_with_config;
+use crate::atom::{FilingEntry, parse_atom_feed};
 use crate::edgar::EdgarParser;
 use crate::error::EDGARParserError;
+use crate::filing_type_builder::action::Action;
+use crate::filing_type_builder::category::FilingCategory;
 use crate::filing_type_builder::filing::FilingTypeOption;
-use crate::filing_type_builder::owner::OwnerOption;
+use crate::filing_type_builder::owner::{OwnerOption, OwnerPerspective};
 use chrono::NaiveDate;
 use serde_xml_rs::from_str as xml_from_str;
+use std::cmp::Reverse;
+use std::collections::HashSet;
 use url::Url;
 
+// Number of results requested when no count is set.
+const DEFAULT_COUNT: u32 = 10;
+
+// Largest number of results the SEC returns for a single browse request.
+const MAX_COUNT: u32 = 100;
+
 /// `EdgarFilingQueryBuilder` is a builder struct to construct a URL query for the SEC's EDGAR system.
+///
+/// Prefer configuring it through the chained setters, e.g.
+/// `EdgarFilingQueryBuilder::new(parser).filing_type(FilingTypeOption::_10K).dateb("20231231")`,
+/// over assigning the public fields, which remain public for compatibility.
 #[derive(Debug, PartialEq)]
 pub struct EdgarFilingQueryBuilder {
     // Instance of EdgarParser that provides the CIK and potentially other metadata.
     pub edgar_parser: EdgarParser,
 
+    // Browse action to perform (e.g., a single company's filings or the latest filings).
+    pub action: Action,
+
     // Type of filing to search for (e.g., 10-K, 8-K).
     pub filing_type: FilingTypeOption,
 
+    // Category of filings to search for, expanded into one query per member form type.
+    pub category: Option<FilingCategory>,
+
     // Date to search filings before, in the format YYYYMMDD.
     pub dateb: String,
 
-    // Ownership option (e.g., include or exclude insider ownership).
+    // Date to search filings from, in the format YYYYMMDD. Left out of the query when empty.
+    pub datea: String,
+
+    // Ownership option (e.g., include or exclude insider ownership). Also set by
+    // `with_perspective`.
     pub owner: OwnerOption,
 
     // Base URL for EDGAR search.
     pub base_url: String,
 
-    // Number of filings to fetch.
-    pub count: String,
+    // Number of filings to fetch, between 1 and `MAX_COUNT`. Set through `set_count` or
+    // `with_count`.
+    count: u32,
 
     // Optional text to filter search results.
     pub search_text: String,
+
+    // Return only the newest entry from the fetch methods. Set through `latest_only`.
+    latest_only: bool,
 }
 
 impl EdgarFilingQueryBuilder {
     /// Constructs a new instance of `EdgarFilingQueryBuilder` with default values and a provided `EdgarParser`.
     pub fn new(edgar_parser: EdgarParser) -> Self {
         Self {
-            base_url: "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&".to_string(),
+            base_url: "https://www.sec.gov/cgi-bin/browse-edgar?".to_string(),
+            action: Default::default(),
             filing_type: Default::default(),
+            category: None,
             dateb: Default::default(),
+            datea: Default::default(),
             owner: Default::default(),
-            count: "10".to_string(),
+            count: DEFAULT_COUNT,
             search_text: Default::default(),
+            latest_only: false,
             edgar_parser,
         }
     }
 
+    /// Returns the number of results requested from the SEC, `10` unless changed.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Sets the number of results requested from the SEC.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::InvalidCount` unless `count` is between 1 and 100; the
+    /// current count is left unchanged.
+    pub fn set_count(&mut self, count: u32) -> Result<(), EDGARParserError> {
+        if !(1..=MAX_COUNT).contains(&count) {
+            return Err(EDGARParserError::InvalidCount(count));
+        }
+        self.count = count;
+        Ok(())
+    }
+
+    /// Sets the type of filing to search for, e.g. `FilingTypeOption::_10K`.
+    pub fn filing_type(mut self, filing_type: FilingTypeOption) -> Self {
+        self.filing_type = filing_type;
+        self
+    }
+
+    /// Sets the date to search filings before, formatted `YYYYMMDD`. It is validated by `build`.
+    pub fn dateb(mut self, dateb: &str) -> Self {
+        self.dateb = dateb.to_string();
+        self
+    }
+
+    /// Sets the date to search filings before from a `NaiveDate`, formatting it as `YYYYMMDD`.
+    pub fn dateb_date(self, date: NaiveDate) -> Self {
+        self.dateb(&date.format("%Y%m%d").to_string())
+    }
+
+    /// Sets the date to search filings from, formatted `YYYYMMDD`, so that together with
+    /// `dateb` the query covers a date range. It is validated by `build`, which also rejects
+    /// a `datea` after `dateb`.
+    pub fn datea(mut self, datea: &str) -> Self {
+        self.datea = datea.to_string();
+        self
+    }
+
+    /// Sets the date to search filings from using a `NaiveDate`, formatting it as `YYYYMMDD`.
+    pub fn datea_date(self, date: NaiveDate) -> Self {
+        self.datea(&date.format("%Y%m%d").to_string())
+    }
+
+    /// Sets whether insider ownership filings are included.
+    pub fn owner(mut self, owner: OwnerOption) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the number of results requested from the SEC. Unlike `set_count`, the range is
+    /// checked by `build`, so the call can be chained.
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the text to filter search results by.
+    pub fn search_text(mut self, search_text: &str) -> Self {
+        self.search_text = search_text.to_string();
+        self
+    }
+
+    /// Requests a single result and makes the fetch methods return only the newest entry,
+    /// e.g. the latest 10-K.
+    pub fn latest_only(mut self) -> Self {
+        self.count = 1;
+        self.latest_only = true;
+        self
+    }
+
+    /// Sets a filing category, so `fetch_category_filings` queries every form type in it.
+    pub fn with_category(mut self, category: FilingCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Queries ownership filings from `perspective`, i.e. with the CIK as the issuer or as the
+    /// reporting owner. This sets `owner` to `perspective.owner_option()`, so whichever of
+    /// `with_perspective` and `owner` is called last decides the `owner` parameter.
+    pub fn with_perspective(self, perspective: OwnerPerspective) -> Self {
+        self.owner(perspective.owner_option())
+    }
+
     /// Builds and returns a `Url` to query the EDGAR system based on the builder's state.
-    /// Returns an error if any component is invalid (e.g., date format or URL parsing fails).
+    /// Returns an error if any component is invalid (e.g., count, date format or URL parsing fails).
     pub fn build(&self) -> Result<Url, EDGARParserError> {
+        if !(1..=MAX_COUNT).contains(&self.count) {
+            return Err(EDGARParserError::InvalidCount(self.count));
+        }
+
         // Convert enums to string representations.
         let filing_type_string = self.filing_type.to_string();
         let owner_string = self.owner.to_string();
 
-        // Validate and extract the date string.
-        let dateb_string = Self::set_and_validate_dateb(self.dateb.clone())?;
-
-        // Format the full URL string with all parameters.
-        let url = format!(
-            "{base}CIK={cik}&type={filing_type_string}&dateb={dateb_string}&owner={owner_string}&count={count}&search_text={search_text}&output=atom",
-            base = self.base_url,
-            cik = self.edgar_parser.cik_str.unwrap(),
-            filing_type_string = filing_type_string,
-            dateb_string = dateb_string,
-            owner_string = owner_string,
-            count = self.count,
-            search_text = self.search_text
-        );
+        let url = match self.action {
+            Action::GetCompany => {
+                let cik = self.edgar_parser.cik_str.ok_or_else(|| {
+                    EDGARParserError::IncompatibleQuery(format!(
+                        "action {} requires a CIK",
+                        self.action
+                    ))
+                })?;
+
+                // Validate and extract the date strings.
+                let dateb_string = Self::validate_date_param(self.dateb.clone())?;
+                let datea_string = Self::validate_date_param(self.datea.clone())?;
+                // Dates are YYYYMMDD, so comparing the strings compares the dates.
+                if !datea_string.is_empty()
+                    && !dateb_string.is_empty()
+                    && datea_string > dateb_string
+                {
+                    return Err(EDGARParserError::IncompatibleQuery(format!(
+                        "datea {} is after dateb {}",
+                        datea_string, dateb_string
+                    )));
+                }
+                let datea_param = if datea_string.is_empty() {
+                    String::new()
+                } else {
+                    format!("&datea={}", datea_string)
+                };
+
+                // Format the full URL string with all parameters.
+                format!(
+                    "{base}action={action}&CIK={cik}&type={filing_type_string}&dateb={dateb_string}{datea_param}&owner={owner_string}&count={count}&search_text={search_text}&output=atom",
+                    base = self.base_url,
+                    action = self.action,
+                    cik = cik,
+                    filing_type_string = filing_type_string,
+                    dateb_string = dateb_string,
+                    datea_param = datea_param,
+                    owner_string = owner_string,
+                    count = self.count,
+                    search_text = self.search_text
+                )
+            }
+            Action::GetCurrent => {
+                // The current events feed spans every company, so a CIK cannot narrow it.
+                if let Some(cik) = self.edgar_parser.cik_str {
+                    return Err(EDGARParserError::IncompatibleQuery(format!(
+                        "action {} lists filings across all companies and cannot be combined with CIK {}",
+                        self.action, cik
+                    )));
+                }
+                // Nor does the feed take date or text filters, which the SEC would ignore.
+                for (param, value) in [
+                    ("dateb", &self.dateb),
+                    ("datea", &self.datea),
+                    ("search_text", &self.search_text),
+                ] {
+                    if !value.is_empty() {
+                        return Err(EDGARParserError::IncompatibleQuery(format!(
+                            "action {} cannot be combined with {} {}",
+                            self.action, param, value
+                        )));
+                    }
+                }
+
+                format!(
+                    "{base}action={action}&type={filing_type_string}&owner={owner_string}&count={count}&output=atom",
+                    base = self.base_url,
+                    action = self.action,
+                    filing_type_string = filing_type_string,
+                    owner_string = owner_string,
+                    count = self.count
+                )
+            }
+        };
 
         // Parse the constructed string into a `Url` object.
         let query = Url::parse(&url)?;
         Ok(query)
     }
 
-    /// Validates the `dateb` string to ensure it is exactly 8 digits and forms a valid date (YYYYMMDD).
-    /// Returns the valid date string or an `EDGARParserError` if invalid.
-    fn set_and_validate_dateb(dateb: String) -> Result<String, EDGARParserError> {
+    /// Validates a `dateb` or `datea` string to ensure it is exactly 8 digits and forms a valid
+    /// date (YYYYMMDD). An empty string, the builder's default, means no date filter and is
+    /// passed through. Returns the valid date string or an `EDGARParserError` if invalid.
+    fn validate_date_param(date: String) -> Result<String, EDGARParserError> {
+        // An empty date leaves the parameter blank, which the SEC treats as all dates.
+        if date.is_empty() {
+            return Ok(date);
+        }
+
         // Check if the string has exactly 8 numeric characters.
-        if dateb.len() != 8 || !dateb.chars().all(|f| f.is_digit(10)) {
-            return Err(EDGARParserError::InvalidDateFormat(dateb));
+        if date.len() != 8 || !date.chars().all(|f| f.is_ascii_digit()) {
+            Err(EDGARParserError::InvalidDateFormat(date))
         } else {
             // Attempt to parse the string as a date.
-            match NaiveDate::parse_from_str(&dateb, "%Y%m%d") {
-                Ok(_) => Ok(dateb),
-                Err(_) => Err(EDGARParserError::InvalidDateFormat(dateb)),
+            match NaiveDate::parse_from_str(&date, "%Y%m%d") {
+                Ok(_) => Ok(date),
+                Err(_) => Err(EDGARParserError::InvalidDateFormat(date)),
             }
         }
     }
 
+    /// Runs the query and parses the returned Atom feed into filing entries.
+    pub async fn fetch_filings(&self) -> Result<Vec<FilingEntry>, EDGARParserError> {
+        let url = self.build()?;
+        let body = fetch_http_body_with_config(url.as_str(), &self.edgar_parser.config)
+            .await
+            .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+        let mut entries = parse_atom_feed(&body)?;
+
+        if self.latest_only {
+            entries.sort_by_key(|entry| Reverse(entry.updated_at()));
+            entries.truncate(1);
+        }
+        Ok(entries)
+    }
+
+    /// Runs one query per form type in the configured category (or just `filing_type` when no
+    /// category is set). Entries are de-duplicated by accession number and returned newest
+    /// first.
+    pub async fn fetch_category_filings(&self) -> Result<Vec<FilingEntry>, EDGARParserError> {
+        let forms = match self.category {
+            Some(category) => category.members().to_vec(),
+            None => vec![self.filing_type],
+        };
+
+        let mut seen = HashSet::new();
+        let mut merged = Vec::new();
+
+        for form in forms {
+            let url = self.build_for_filing_type(form)?;
+            let body = fetch_http_body_with_config(url.as_str(), &self.edgar_parser.config)
+                .await
+                .map_err(|op: Box<dyn std::error::Error>| EDGARParserError::HttpError(op))?;
+
+            for entry in parse_atom_feed(&body)? {
+                if seen.insert(entry.accession_number.clone()) {
+                    merged.push(entry);
+                }
+            }
+        }
+
+        merged.sort_by_key(|entry| Reverse(entry.updated_at()));
+        if self.latest_only {
+            merged.truncate(1);
+        }
+        Ok(merged)
+    }
+
+    /// Builds the query URL with `filing_type` substituted for the builder's own.
+    fn build_for_filing_type(
+        &self,
+        filing_type: FilingTypeOption,
+    ) -> Result<Url, EDGARParserError> {
+        let mut url = self.build()?;
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| {
+                let v = if k == "type" {
+                    filing_type.to_string()
+                } else {
+                    v.into_owned()
+                };
+                (k.into_owned(), v)
+            })
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+        Ok(url)
+    }
+
     pub async fn fetch_filing_type(&self) -> Result<String, Box<dyn std::error::Error>> {
         let url = self.build()?;
         let url_string = url.to_string();
-        let body = fetch_http_body(&url_string).await?;
+        let body = fetch_http_body_with_config(&url_string, &self.edgar_parser.config).await?;
         let parsed = xml_from_str(&body)?;
         Ok(parsed)
     }
@@ -100,11 +359,24 @@ impl EdgarFilingQueryBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::EdgarConfig;
     use crate::edgar::EdgarParser;
     use crate::error::EDGARParserError;
     use crate::filing_type_builder::filing::FilingTypeOption;
     use crate::filing_type_builder::owner::OwnerOption;
 
+    fn offline_parser(cik_str: Option<u32>) -> EdgarParser {
+        EdgarParser {
+            cik_str,
+            ticker: None,
+            title: None,
+            submissions: None,
+            company_facts: None,
+            leading_zero_cik: cik_str.map(|c| format!("{:010}", c)).unwrap_or_default(),
+            config: EdgarConfig::for_tests(),
+        }
+    }
+
     async fn sample_parser() -> Result<EdgarParser, EDGARParserError> {
         let parser = EdgarParser::create_from_ticker("AAPL").await?;
         println!("Sample parser created: {:?}", parser);
@@ -120,32 +392,177 @@ mod tests {
         assert_eq!(cik_raw_num, 320193);
         assert_eq!(
             builder.base_url,
-            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&"
+            "https://www.sec.gov/cgi-bin/browse-edgar?"
         );
-        assert_eq!(builder.count, "10");
+        assert_eq!(builder.action, Action::GetCompany);
+        assert_eq!(builder.count(), 10);
         assert_eq!(builder.dateb, "");
         assert_eq!(builder.search_text, "");
     }
 
     #[test]
-    fn test_set_and_validate_dateb_valid() {
+    fn test_set_count_valid() {
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)));
+        assert_eq!(builder.count(), 10);
+
+        builder.set_count(1).unwrap();
+        assert_eq!(builder.count(), 1);
+        builder.set_count(100).unwrap();
+        assert_eq!(builder.count(), 100);
+        builder.dateb = "20240101".to_string();
+        assert!(builder.build().unwrap().as_str().contains("count=100"));
+    }
+
+    #[test]
+    fn test_build_through_chained_setters() {
+        let url = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .filing_type(FilingTypeOption::_10K)
+            .dateb("20231231")
+            .owner(OwnerOption::EXCLUDE)
+            .with_count(25)
+            .search_text("annual")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=320193&type=10-K&dateb=20231231&owner=exclude&count=25&search_text=annual&output=atom"
+        );
+
+        let too_many = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .dateb("20231231")
+            .with_count(101)
+            .build();
+        assert!(matches!(too_many, Err(EDGARParserError::InvalidCount(101))));
+    }
+
+    #[test]
+    fn test_perspective_composes_owner_parameter() {
+        let url_for = |perspective| {
+            let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+                .with_perspective(perspective);
+            builder.filing_type = FilingTypeOption::_4;
+            builder.dateb = "20240101".to_string();
+            builder.build().unwrap().to_string()
+        };
+
+        assert_eq!(
+            url_for(OwnerPerspective::Issuer),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=320193&type=4&dateb=20240101&owner=only&count=10&search_text=&output=atom"
+        );
+        assert_eq!(
+            url_for(OwnerPerspective::ReportingOwner),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=320193&type=4&dateb=20240101&owner=include&count=10&search_text=&output=atom"
+        );
+
+        // A later `owner` call replaces the perspective's choice rather than being ignored.
+        let builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .with_perspective(OwnerPerspective::Issuer)
+            .owner(OwnerOption::EXCLUDE);
+        assert_eq!(builder.owner, OwnerOption::EXCLUDE);
+        let builder = builder.with_perspective(OwnerPerspective::Issuer);
+        assert_eq!(builder.owner, OwnerOption::ONLY);
+    }
+
+    #[test]
+    fn test_set_count_out_of_range() {
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)));
+        builder.set_count(40).unwrap();
+
+        for invalid in [0, 101] {
+            let err = builder.set_count(invalid).unwrap_err();
+            assert!(matches!(err, EDGARParserError::InvalidCount(c) if c == invalid));
+        }
+        assert_eq!(builder.count(), 40);
+    }
+
+    #[test]
+    fn test_validate_date_param_valid() {
         let date = "20240101".to_string();
-        let result = EdgarFilingQueryBuilder::set_and_validate_dateb(date.clone());
+        let result = EdgarFilingQueryBuilder::validate_date_param(date.clone());
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), date);
     }
 
     #[test]
-    fn test_set_and_validate_dateb_invalid_format() {
+    fn test_build_default_builder_without_date() {
+        let builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)));
+
+        assert_eq!(
+            builder.build().unwrap().as_str(),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=320193&type=1-U&dateb=&owner=include&count=10&search_text=&output=atom"
+        );
+    }
+
+    #[test]
+    fn test_build_date_range() {
+        let url = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .filing_type(FilingTypeOption::_8K)
+            .datea("20230101")
+            .dateb("20231231")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=320193&type=8-K&dateb=20231231&datea=20230101&owner=include&count=10&search_text=&output=atom"
+        );
+    }
+
+    #[test]
+    fn test_build_with_naive_dates() {
+        let url = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .datea_date(NaiveDate::from_ymd_opt(2023, 1, 5).unwrap())
+            .dateb_date(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap())
+            .build()
+            .unwrap();
+
+        assert!(url.as_str().contains("&dateb=20231231&datea=20230105&"));
+    }
+
+    #[test]
+    fn test_build_invalid_datea() {
+        let result = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .datea("2023-01-01")
+            .dateb("20231231")
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(EDGARParserError::InvalidDateFormat(d)) if d == "2023-01-01"
+        ));
+    }
+
+    #[test]
+    fn test_build_rejects_inverted_date_range() {
+        let result = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .datea("20240101")
+            .dateb("20231231")
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(EDGARParserError::IncompatibleQuery(m)) if m.contains("20240101") && m.contains("20231231")
+        ));
+
+        let same_day = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .datea("20231231")
+            .dateb("20231231")
+            .build();
+        assert!(same_day.is_ok());
+    }
+
+    #[test]
+    fn test_validate_date_param_invalid_format() {
         let bad_date = "2024011".to_string(); // only 7 digits
-        let err = EdgarFilingQueryBuilder::set_and_validate_dateb(bad_date.clone()).unwrap_err();
+        let err = EdgarFilingQueryBuilder::validate_date_param(bad_date.clone()).unwrap_err();
         assert!(matches!(err, EDGARParserError::InvalidDateFormat(d) if d == bad_date));
     }
 
     #[test]
-    fn test_set_and_validate_dateb_invalid_date() {
+    fn test_validate_date_param_invalid_date() {
         let bad_date = "20241301".to_string(); // month 13
-        let err = EdgarFilingQueryBuilder::set_and_validate_dateb(bad_date.clone()).unwrap_err();
+        let err = EdgarFilingQueryBuilder::validate_date_param(bad_date.clone()).unwrap_err();
         assert!(matches!(err, EDGARParserError::InvalidDateFormat(d) if d == bad_date));
     }
 
@@ -156,21 +573,212 @@ mod tests {
         builder.filing_type = FilingTypeOption::_10K;
         builder.owner = OwnerOption::INCLUDE;
         builder.dateb = "20231231".to_string();
-        builder.count = "25".to_string();
+        builder.set_count(25).unwrap();
         builder.search_text = "apple".to_string();
 
         let url = builder.build().unwrap();
         let url_str = url.as_str();
 
+        assert!(url_str.contains("action=getcompany"));
         assert!(url_str.contains("CIK=320193"));
         assert!(url_str.contains("type=10-K"));
         assert!(url_str.contains("dateb=20231231"));
-        assert!(url_str.contains("owner=INCLUDE"));
+        assert!(url_str.contains("owner=include"));
         assert!(url_str.contains("count=25"));
         assert!(url_str.contains("search_text=apple"));
         assert!(url_str.contains("output=atom"));
     }
 
+    #[test]
+    fn test_build_get_current_without_cik() {
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(None));
+        builder.action = Action::GetCurrent;
+        builder.filing_type = FilingTypeOption::_8K;
+
+        let url = builder.build().unwrap();
+        let url_str = url.as_str();
+
+        assert!(url_str.contains("action=getcurrent"));
+        assert!(url_str.contains("type=8-K"));
+        assert!(url_str.contains("owner=include"));
+        assert!(!url_str.contains("CIK="));
+    }
+
+    #[test]
+    fn test_build_get_current_rejects_cik() {
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)));
+        builder.action = Action::GetCurrent;
+
+        let result = builder.build();
+        assert!(matches!(
+            result,
+            Err(EDGARParserError::IncompatibleQuery(msg)) if msg.contains("CIK 320193")
+        ));
+    }
+
+    #[test]
+    fn test_build_get_current_rejects_filters() {
+        let rejected = [
+            EdgarFilingQueryBuilder::new(offline_parser(None)).dateb("20231231"),
+            EdgarFilingQueryBuilder::new(offline_parser(None)).datea("20230101"),
+            EdgarFilingQueryBuilder::new(offline_parser(None)).search_text("apple"),
+        ];
+
+        for mut builder in rejected {
+            builder.action = Action::GetCurrent;
+            assert!(matches!(
+                builder.build(),
+                Err(EDGARParserError::IncompatibleQuery(msg)) if msg.contains("getcurrent")
+            ));
+        }
+    }
+
+    #[test]
+    fn test_build_get_company_requires_cik() {
+        let builder = EdgarFilingQueryBuilder::new(offline_parser(None));
+
+        let result = builder.build();
+        assert!(matches!(
+            result,
+            Err(EDGARParserError::IncompatibleQuery(msg)) if msg == "action getcompany requires a CIK"
+        ));
+    }
+
+    fn atom_feed(entries: &[(&str, &str, &str)]) -> String {
+        let entries: String = entries
+            .iter()
+            .map(|(form, accn, updated)| {
+                format!(
+                    r#"<entry>
+<title>{form} - Annual report</title>
+<link rel="alternate" type="text/html" href="https://www.sec.gov/Archives/edgar/data/320193/{accn}-index.htm"/>
+<updated>{updated}</updated>
+<category scheme="https://www.sec.gov/" label="form type" term="{form}"/>
+<id>urn:tag:sec.gov,2008:accession-number={accn}</id>
+</entry>"#
+                )
+            })
+            .collect();
+        format!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>APPLE INC</title>{entries}</feed>"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fetch_category_filings_merges_forms() {
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mut mocks = Vec::new();
+        for (form, body) in [
+            (
+                "10-K",
+                atom_feed(&[("10-K", "0000320193-23-000106", "2023-11-03T06:01:36-04:00")]),
+            ),
+            (
+                "10-Q",
+                atom_feed(&[
+                    ("10-Q", "0000320193-24-000006", "2024-02-02T06:01:14-05:00"),
+                    ("10-K", "0000320193-23-000106", "2023-11-03T06:01:36-04:00"),
+                ]),
+            ),
+        ] {
+            mocks.push(
+                server
+                    .mock("GET", "/cgi-bin/browse-edgar")
+                    .match_query(Matcher::UrlEncoded("type".into(), form.into()))
+                    .with_status(200)
+                    .with_body(body)
+                    .create_async()
+                    .await,
+            );
+        }
+        for form in ["20-F", "40-F"] {
+            mocks.push(
+                server
+                    .mock("GET", "/cgi-bin/browse-edgar")
+                    .match_query(Matcher::UrlEncoded("type".into(), form.into()))
+                    .with_status(200)
+                    .with_body(atom_feed(&[]))
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193)))
+            .with_category(FilingCategory::PeriodicReports);
+        builder.base_url = format!("{}/cgi-bin/browse-edgar?", server.url());
+        builder.dateb = "20240301".to_string();
+
+        let entries = builder.fetch_category_filings().await.unwrap();
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+
+        let accessions: Vec<&str> = entries
+            .iter()
+            .map(|e| e.accession_number.as_str())
+            .collect();
+        assert_eq!(
+            accessions,
+            vec!["0000320193-24-000006", "0000320193-23-000106"]
+        );
+        assert_eq!(entries[0].form, "10-Q");
+        assert_eq!(entries[1].form, "10-K");
+    }
+
+    #[tokio::test]
+    async fn test_latest_only_returns_newest_entry() {
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/cgi-bin/browse-edgar")
+            .match_query(Matcher::UrlEncoded("count".into(), "1".into()))
+            .with_status(200)
+            .with_body(atom_feed(&[
+                ("10-K", "0000320193-22-000108", "2022-10-28T06:01:14-04:00"),
+                ("10-K", "0000320193-23-000106", "2023-11-03T06:01:36-04:00"),
+            ]))
+            .create_async()
+            .await;
+
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193))).latest_only();
+        builder.filing_type = FilingTypeOption::_10K;
+        builder.base_url = format!("{}/cgi-bin/browse-edgar?", server.url());
+        builder.dateb = "20240301".to_string();
+
+        let entries = builder.fetch_filings().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(builder.count(), 1);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].accession_number, "0000320193-23-000106");
+    }
+
+    #[tokio::test]
+    async fn test_latest_only_compares_updated_across_offsets() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/cgi-bin/browse-edgar")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body(atom_feed(&[
+                ("8-K", "0000320193-24-000001", "2024-02-01T20:00:00+00:00"),
+                ("8-K", "0000320193-24-000002", "2024-02-01T16:30:41-05:00"),
+            ]))
+            .create_async()
+            .await;
+
+        let mut builder = EdgarFilingQueryBuilder::new(offline_parser(Some(320193))).latest_only();
+        builder.base_url = format!("{}/cgi-bin/browse-edgar?", server.url());
+
+        let entries = builder.fetch_filings().await.unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].accession_number, "0000320193-24-000002");
+    }
+
     #[tokio::test]
     async fn test_build_url_invalid_date() {
         let parser = sample_parser().await.unwrap();
@@ -191,9 +799,6 @@ mod tests {
         builder.dateb = "invalid".to_string(); // Will cause build() to fail
 
         let result = builder.fetch_filing_type().await;
-        assert!(matches!(
-            result,
-            Err(_)
-        ));
+        assert!(result.is_err());
     }
 }
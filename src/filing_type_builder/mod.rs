@@ -1,4 +1,11 @@
-mod filing_type_builder;
+mod action;
+mod category;
 mod filing;
+#[allow(clippy::module_inception)]
+mod filing_type_builder;
 mod owner;
+pub use action::Action;
+pub use category::FilingCategory;
+pub use filing::FilingTypeOption;
 pub use filing_type_builder::EdgarFilingQueryBuilder;
+pub use owner::{OwnerOption, OwnerPerspective};
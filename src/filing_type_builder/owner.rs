@@ -3,10 +3,11 @@ use phf::{Map, phf_map};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-#[allow(missing_docs)]
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[allow(missing_docs, clippy::upper_case_acronyms)]
 pub enum OwnerOption {
     /// "INCLUDE" means INCLUDE all documents regardless of the source.
+    #[default]
     INCLUDE,
     /// "EXCLUDE" means EXCLUDE documents related to the company's director or officer ownership.
     EXCLUDE,
@@ -35,23 +36,40 @@ impl FromStr for OwnerOption {
     }
 }
 
-impl Default for OwnerOption {
-    fn default() -> Self {
-        OwnerOption::INCLUDE
-    }
-}
-
 impl fmt::Display for OwnerOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
-            OwnerOption::INCLUDE => "INCLUDE".to_string(),
-            OwnerOption::EXCLUDE => "EXCLUDE".to_string(),
-            OwnerOption::ONLY => "ONLY".to_string(),
+            OwnerOption::INCLUDE => "include".to_string(),
+            OwnerOption::EXCLUDE => "exclude".to_string(),
+            OwnerOption::ONLY => "only".to_string(),
         };
         write!(f, "{}", value)
     }
 }
 
+/// Which side of an ownership filing (Forms 3, 4 and 5) the queried CIK is on. EDGAR lists
+/// these filings under both the issuer and each reporting owner, and the `owner` parameter
+/// decides which of them a company query returns.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum OwnerPerspective {
+    /// The CIK is the issuer whose securities were traded; only the insiders' ownership
+    /// filings about it are listed (`owner=only`).
+    Issuer,
+    /// The CIK is the insider or fund that filed as reporting owner; its own ownership
+    /// filings must be included alongside any company filings (`owner=include`).
+    ReportingOwner,
+}
+
+impl OwnerPerspective {
+    /// Returns the `owner` parameter EDGAR needs to list filings from this perspective.
+    pub fn owner_option(self) -> OwnerOption {
+        match self {
+            OwnerPerspective::Issuer => OwnerOption::ONLY,
+            OwnerPerspective::ReportingOwner => OwnerOption::INCLUDE,
+        }
+    }
+}
+
 #[allow(unused)]
 pub fn owner_from_str(s: &str) -> Result<OwnerOption, EDGARParserError> {
     OwnerOption::from_str(s)
@@ -62,6 +80,7 @@ pub fn to_string(owner_type: OwnerOption) -> String {
     owner_type.to_string()
 }
 
+/// Validates an owner string and returns its canonical lowercase form, as used in EDGAR URLs.
 #[allow(unused)]
 pub fn validate_owner_type_string(s: &str) -> Result<String, EDGARParserError> {
     let ft = owner_from_str(s)?;
@@ -94,7 +113,7 @@ mod tests {
     #[test]
     fn test_display_trait() {
         let ft: OwnerOption = OwnerOption::INCLUDE;
-        assert_eq!(ft.to_string(), "INCLUDE");
+        assert_eq!(ft.to_string(), "include");
     }
 
     #[test]
@@ -106,13 +125,33 @@ mod tests {
     #[test]
     fn test_to_string_wrapper() {
         let s: String = to_string(OwnerOption::EXCLUDE);
-        assert_eq!(s, "EXCLUDE");
+        assert_eq!(s, "exclude");
     }
 
     #[test]
     fn test_validate_owner_type_string_valid() {
         let s: String = validate_owner_type_string("EXCLUDE").unwrap();
-        assert_eq!(s, "EXCLUDE");
+        assert_eq!(s, "exclude");
+    }
+
+    #[test]
+    fn test_validate_owner_type_string_canonical_lowercase() {
+        let s: String = validate_owner_type_string("Include").unwrap();
+        assert_eq!(s, "include");
+    }
+
+    #[test]
+    fn test_perspective_owner_option() {
+        assert_eq!(OwnerPerspective::Issuer.owner_option(), OwnerOption::ONLY);
+        assert_eq!(
+            OwnerPerspective::ReportingOwner.owner_option(),
+            OwnerOption::INCLUDE
+        );
+    }
+
+    #[test]
+    fn test_default_owner() {
+        assert_eq!(OwnerOption::default(), OwnerOption::INCLUDE);
     }
 
     #[test]
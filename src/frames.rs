@@ -0,0 +1,440 @@
+use crate::config::DATA_BASE_URL;
+use crate::error::EDGARParserError;
+use chrono::NaiveDate;
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+use url::Url;
+
+/// The calendar period an XBRL frame aggregates, as used in the frames API path.
+///
+/// # Variants
+/// - `Annual(year)`: A calendar year of duration data, e.g. `CY2020`.
+/// - `Quarterly(year, quarter)`: A calendar quarter of duration data, e.g. `CY2020Q1`.
+/// - `Instantaneous(year, quarter)`: Point-in-time data at the end of a quarter, e.g. `CY2020Q1I`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Period {
+    Annual(u16),
+    Quarterly(u16, u8),
+    Instantaneous(u16, u8),
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Period::Annual(year) => write!(f, "CY{}", year),
+            Period::Quarterly(year, quarter) => write!(f, "CY{}Q{}", year, quarter),
+            Period::Instantaneous(year, quarter) => write!(f, "CY{}Q{}I", year, quarter),
+        }
+    }
+}
+
+impl FromStr for Period {
+    type Err = EDGARParserError;
+
+    /// Parses a frames period token such as `CY2020`, `CY2020Q1` or `CY2020Q1I`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || EDGARParserError::InvalidFramePeriod(s.to_string());
+
+        let rest = s.strip_prefix("CY").ok_or_else(invalid)?;
+        let (rest, instantaneous) = match rest.strip_suffix('I') {
+            Some(rest) => (rest, true),
+            None => (rest, false),
+        };
+
+        let (year, quarter) = match rest.split_once('Q') {
+            Some((year, quarter)) => (year, Some(quarter)),
+            None => (rest, None),
+        };
+        if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let year: u16 = year.parse().map_err(|_| invalid())?;
+
+        match (quarter, instantaneous) {
+            (None, false) => Ok(Period::Annual(year)),
+            (Some(quarter), _) => {
+                let quarter = match quarter {
+                    "1" => 1,
+                    "2" => 2,
+                    "3" => 3,
+                    "4" => 4,
+                    _ => return Err(invalid()),
+                };
+                if instantaneous {
+                    Ok(Period::Instantaneous(year, quarter))
+                } else {
+                    Ok(Period::Quarterly(year, quarter))
+                }
+            }
+            (None, true) => Err(invalid()),
+        }
+    }
+}
+
+/// An XBRL taxonomy that frames can be requested from.
+///
+/// # Variants
+/// - `UsGaap`: US GAAP financial statement concepts, e.g. `Revenues`.
+/// - `Dei`: Document and entity information, e.g. `EntityCommonStockSharesOutstanding`.
+/// - `IfrsFull`: IFRS concepts reported by foreign private issuers.
+/// - `Srt`: SEC reporting taxonomy concepts shared across financial statements.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Taxonomy {
+    #[default]
+    UsGaap,
+    Dei,
+    IfrsFull,
+    Srt,
+}
+
+impl Taxonomy {
+    /// Returns the taxonomy's segment of the frames URL, e.g. `us-gaap`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Taxonomy::UsGaap => "us-gaap",
+            Taxonomy::Dei => "dei",
+            Taxonomy::IfrsFull => "ifrs-full",
+            Taxonomy::Srt => "srt",
+        }
+    }
+}
+
+impl fmt::Display for Taxonomy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Builds the XBRL frames API URL for a concept, unit and period without issuing a request,
+/// e.g. `https://data.sec.gov/api/xbrl/frames/us-gaap/Revenues/USD/CY2023Q1.json`.
+///
+/// # Errors
+/// Returns `EDGARParserError::UrlParseError` if the components do not form a valid URL.
+pub fn frames_url(
+    taxonomy: Taxonomy,
+    fact: &str,
+    unit: &str,
+    period: Period,
+) -> Result<Url, EDGARParserError> {
+    frames_url_at(DATA_BASE_URL, taxonomy, fact, unit, period)
+}
+
+/// Builds the frames URL under an alternate data API base URL.
+pub(crate) fn frames_url_at(
+    data_base_url: &str,
+    taxonomy: Taxonomy,
+    fact: &str,
+    unit: &str,
+    period: Period,
+) -> Result<Url, EDGARParserError> {
+    let url = Url::parse(&format!(
+        "{}/api/xbrl/frames/{}/{}/{}/{}.json",
+        data_base_url, taxonomy, fact, unit, period
+    ))?;
+    Ok(url)
+}
+
+/// Represents one reporting entity's value within an XBRL frame.
+///
+/// # Fields
+/// - `accn`: Accession number of the filing the fact was taken from.
+/// - `cik`: The entity's Central Index Key (CIK) number.
+/// - `entity_name`: The entity's registered name.
+/// - `loc`: The entity's location code, e.g. `US-CA`.
+/// - `start`: Start of the reporting period. Instantaneous facts (`CY####Q#I` frames) have
+///   no start, so it is `None` for them.
+/// - `end`: End of the reporting period, or the instant the fact was measured.
+/// - `val`: The reported value, in the frame's unit of measure.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameDatum {
+    pub accn: String,
+    pub cik: u32,
+    pub entity_name: String,
+    pub loc: String,
+    #[serde(default)]
+    pub start: Option<NaiveDate>,
+    pub end: NaiveDate,
+    pub val: f64,
+}
+
+/// Represents the metadata of an XBRL frames response, without its `data` array.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct FrameHeader {
+    pub taxonomy: String,
+    pub tag: String,
+    pub ccp: String,
+    pub uom: String,
+    pub label: String,
+    pub description: String,
+    pub pts: u32,
+}
+
+/// Represents a complete XBRL frames response: its metadata and every entity's value.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct XbrlFrame {
+    #[serde(flatten)]
+    pub header: FrameHeader,
+    #[serde(default)]
+    pub data: Vec<FrameDatum>,
+}
+
+impl XbrlFrame {
+    /// Returns the 1-based rank of `cik` by descending `val`, together with the number of
+    /// entities in the frame. Entities with equal values share a rank. Returns `None` when
+    /// the CIK is not part of the frame.
+    pub fn rank_of(&self, cik: u32) -> Option<(usize, usize)> {
+        let val = self.data.iter().find(|d| d.cik == cik)?.val;
+        let rank = 1 + self.data.iter().filter(|d| d.val > val).count();
+        Some((rank, self.data.len()))
+    }
+}
+
+/// Streams an XBRL frames JSON document from `reader`, calling `on_datum` for each entry of
+/// its `data` array as it is parsed. Only one `FrameDatum` is held in memory at a time, which
+/// keeps large cross-sections (one fact across every filer) cheap to process.
+///
+/// # Errors
+/// Returns `EDGARParserError::JSONParseError` if the document is not a valid frames response.
+pub fn stream_frames<R, F>(reader: R, on_datum: F) -> Result<FrameHeader, EDGARParserError>
+where
+    R: Read,
+    F: FnMut(FrameDatum),
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let header = deserializer.deserialize_map(FrameVisitor { on_datum })?;
+    deserializer.end()?;
+    Ok(header)
+}
+
+/// Visits the top-level frames object, collecting the header fields and streaming `data`.
+struct FrameVisitor<F> {
+    on_datum: F,
+}
+
+impl<'de, F> Visitor<'de> for FrameVisitor<F>
+where
+    F: FnMut(FrameDatum),
+{
+    type Value = FrameHeader;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an XBRL frames object")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut header = FrameHeader::default();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "taxonomy" => header.taxonomy = map.next_value()?,
+                "tag" => header.tag = map.next_value()?,
+                "ccp" => header.ccp = map.next_value()?,
+                "uom" => header.uom = map.next_value()?,
+                "label" => header.label = map.next_value()?,
+                "description" => header.description = map.next_value()?,
+                "pts" => header.pts = map.next_value()?,
+                "data" => map.next_value_seed(DataSeed {
+                    on_datum: &mut self.on_datum,
+                })?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(header)
+    }
+}
+
+/// Deserializes the `data` array one element at a time, handing each to the callback.
+struct DataSeed<'a, F> {
+    on_datum: &'a mut F,
+}
+
+impl<'de, F> DeserializeSeed<'de> for DataSeed<'_, F>
+where
+    F: FnMut(FrameDatum),
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for DataSeed<'_, F>
+where
+    F: FnMut(FrameDatum),
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of frame data")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while let Some(datum) = seq.next_element::<FrameDatum>()? {
+            (self.on_datum)(datum);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAMES_FIXTURE: &str = r#"
+        {
+            "taxonomy": "us-gaap",
+            "tag": "AccountsPayableCurrent",
+            "ccp": "CY2019Q1I",
+            "uom": "USD",
+            "label": "Accounts Payable, Current",
+            "description": "Carrying value as of the balance sheet date of liabilities incurred.",
+            "pts": 3,
+            "data": [
+                {"accn": "0001104659-19-016320", "cik": 1750, "entityName": "AAR CORP.", "loc": "US-IL", "end": "2019-02-28", "val": 218600000},
+                {"accn": "0001264931-19-000034", "cik": 1800, "entityName": "ABBOTT LABORATORIES", "loc": "US-IL", "end": "2019-03-31", "val": 3101000000},
+                {"accn": "0000002178-19-000035", "cik": 2178, "entityName": "ADAMS RESOURCES & ENERGY, INC.", "loc": "US-TX", "end": "2019-03-31", "val": 125150000}
+            ]
+        }
+    "#;
+
+    #[test]
+    fn test_stream_frames_counts_and_header() {
+        let mut count = 0;
+        let header = stream_frames(FRAMES_FIXTURE.as_bytes(), |_| count += 1).unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(header.tag, "AccountsPayableCurrent");
+        assert_eq!(header.ccp, "CY2019Q1I");
+        assert_eq!(header.uom, "USD");
+        assert_eq!(header.pts, 3);
+    }
+
+    #[test]
+    fn test_stream_frames_sample_datum() {
+        let mut data = Vec::new();
+        stream_frames(FRAMES_FIXTURE.as_bytes(), |d| data.push(d)).unwrap();
+
+        assert_eq!(
+            data[1],
+            FrameDatum {
+                accn: "0001264931-19-000034".to_string(),
+                cik: 1800,
+                entity_name: "ABBOTT LABORATORIES".to_string(),
+                loc: "US-IL".to_string(),
+                start: None,
+                end: NaiveDate::from_ymd_opt(2019, 3, 31).unwrap(),
+                val: 3101000000.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_stream_frames_mixed_instant_and_duration() {
+        let json = r#"
+            {
+                "taxonomy": "us-gaap",
+                "tag": "Revenues",
+                "ccp": "CY2019",
+                "uom": "USD",
+                "pts": 2,
+                "data": [
+                    {"accn": "0001104659-19-016320", "cik": 1750, "entityName": "AAR CORP.", "loc": "US-IL", "end": "2019-05-31", "val": 2051000000},
+                    {"accn": "0001264931-20-000019", "cik": 1800, "entityName": "ABBOTT LABORATORIES", "loc": "US-IL", "start": "2019-01-01", "end": "2019-12-31", "val": 31904000000}
+                ]
+            }
+        "#;
+        let mut data = Vec::new();
+        stream_frames(json.as_bytes(), |d| data.push(d)).unwrap();
+
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].start, None);
+        assert_eq!(data[0].end, NaiveDate::from_ymd_opt(2019, 5, 31).unwrap());
+        assert_eq!(data[1].start, NaiveDate::from_ymd_opt(2019, 1, 1));
+        assert_eq!(data[1].end, NaiveDate::from_ymd_opt(2019, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn test_rank_of() {
+        let frame: XbrlFrame = serde_json::from_str(FRAMES_FIXTURE).unwrap();
+
+        assert_eq!(frame.header.tag, "AccountsPayableCurrent");
+        assert_eq!(frame.rank_of(1800), Some((1, 3)));
+        assert_eq!(frame.rank_of(1750), Some((2, 3)));
+        assert_eq!(frame.rank_of(2178), Some((3, 3)));
+        assert_eq!(frame.rank_of(320193), None);
+    }
+
+    #[test]
+    fn test_taxonomy_url_segments() {
+        assert_eq!(Taxonomy::default().to_string(), "us-gaap");
+        assert_eq!(Taxonomy::Dei.to_string(), "dei");
+        assert_eq!(Taxonomy::IfrsFull.to_string(), "ifrs-full");
+        assert_eq!(Taxonomy::Srt.to_string(), "srt");
+    }
+
+    #[test]
+    fn test_period_round_trip() {
+        let cases = [
+            (Period::Annual(2020), "CY2020"),
+            (Period::Quarterly(2020, 1), "CY2020Q1"),
+            (Period::Instantaneous(2020, 1), "CY2020Q1I"),
+        ];
+
+        for (period, token) in cases {
+            assert_eq!(period.to_string(), token);
+            assert_eq!(token.parse::<Period>().unwrap(), period);
+        }
+    }
+
+    #[test]
+    fn test_period_rejects_invalid_tokens() {
+        for token in ["2020", "CY20", "CY2020Q5", "CY2020I", "CY2020Q1X", "cy2020"] {
+            assert!(
+                matches!(
+                    token.parse::<Period>(),
+                    Err(EDGARParserError::InvalidFramePeriod(_))
+                ),
+                "{} should be rejected",
+                token
+            );
+        }
+    }
+
+    #[test]
+    fn test_frames_url_quarterly() {
+        let url = frames_url(
+            Taxonomy::UsGaap,
+            "Revenues",
+            "USD",
+            Period::Quarterly(2023, 1),
+        )
+        .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://data.sec.gov/api/xbrl/frames/us-gaap/Revenues/USD/CY2023Q1.json"
+        );
+    }
+
+    #[test]
+    fn test_stream_frames_invalid_json() {
+        let result = stream_frames(r#"{"data": [{"cik": "oops"}]}"#.as_bytes(), |_| {});
+        assert!(matches!(result, Err(EDGARParserError::JSONParseError(_))));
+    }
+}
@@ -0,0 +1,191 @@
+use crate::api::{http_error, send_get};
+use crate::config::EdgarConfig;
+use crate::error::EDGARParserError;
+use chrono::NaiveDate;
+use futures_util::stream::{self, Stream};
+
+/// Represents one filing listed in an EDGAR quarterly `master.idx` file.
+///
+/// # Fields
+/// - `cik`: The filer's Central Index Key (CIK) number.
+/// - `company_name`: The filer's name at the time of filing.
+/// - `form_type`: The form type, e.g. `8-K`.
+/// - `date_filed`: The date the filing was made.
+/// - `filename`: Path of the full submission text file, relative to `https://www.sec.gov/Archives/`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct IndexEntry {
+    pub cik: u32,
+    pub company_name: String,
+    pub form_type: String,
+    pub date_filed: NaiveDate,
+    pub filename: String,
+}
+
+impl IndexEntry {
+    /// Parses one `CIK|Company Name|Form Type|Date Filed|Filename` row. Returns `None` for the
+    /// header and separator lines that precede the rows.
+    pub fn parse_line(line: &str) -> Option<Self> {
+        let mut fields = line.trim_end().split('|');
+
+        let cik = fields.next()?.trim().parse().ok()?;
+        let company_name = fields.next()?.trim().to_string();
+        let form_type = fields.next()?.trim().to_string();
+        let date_filed = NaiveDate::parse_from_str(fields.next()?.trim(), "%Y-%m-%d").ok()?;
+        let filename = fields.next()?.trim().to_string();
+
+        Some(Self {
+            cik,
+            company_name,
+            form_type,
+            date_filed,
+            filename,
+        })
+    }
+}
+
+/// State carried between polls of a `master.idx` stream.
+struct IndexStream {
+    url: String,
+    config: EdgarConfig,
+    form: Option<String>,
+    response: Option<reqwest::Response>,
+    buffer: Vec<u8>,
+    received: u64,
+    finished: bool,
+}
+
+impl IndexStream {
+    /// Removes and returns the next complete line in the buffer.
+    fn next_line(&mut self) -> Option<String> {
+        let end = self.buffer.iter().position(|b| *b == b'\n')?;
+        let line: Vec<u8> = self.buffer.drain(..=end).collect();
+        Some(String::from_utf8_lossy(&line).into_owned())
+    }
+
+    /// Reads the next chunk of the body, sending the request on first use. The stream fails
+    /// with `EDGARParserError::ResponseTooLarge` once the body exceeds the config's
+    /// `max_response_bytes`, or up front when `Content-Length` already does.
+    async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, EDGARParserError> {
+        let limit = self.config.max_response_bytes;
+        let too_large = |limit| EDGARParserError::ResponseTooLarge {
+            url: self.url.clone(),
+            limit,
+        };
+
+        if self.response.is_none() {
+            let response = send_get(&self.url, &self.config).await?;
+            if let Some(limit) = limit
+                && response.content_length().is_some_and(|len| len > limit)
+            {
+                return Err(too_large(limit));
+            }
+            self.response = Some(response);
+        }
+
+        let response = self.response.as_mut().expect("response was just set");
+        let chunk = response.chunk().await.map_err(http_error)?;
+        if let Some(chunk) = &chunk {
+            self.received += chunk.len() as u64;
+            if let Some(limit) = limit
+                && self.received > limit
+            {
+                return Err(too_large(limit));
+            }
+        }
+        Ok(chunk.map(|c| c.to_vec()))
+    }
+
+    fn matches(&self, entry: &IndexEntry) -> bool {
+        self.form
+            .as_deref()
+            .is_none_or(|form| entry.form_type == form)
+    }
+}
+
+/// Streams the entries of the `master.idx` at `url` as the body arrives, keeping only one
+/// chunk of the index in memory. When `form` is set, only entries of that form are yielded.
+pub(crate) fn stream_master_index(
+    url: String,
+    form: Option<String>,
+    config: &EdgarConfig,
+) -> impl Stream<Item = Result<IndexEntry, EDGARParserError>> + use<> {
+    let state = IndexStream {
+        url,
+        config: config.clone(),
+        form,
+        response: None,
+        buffer: Vec::new(),
+        received: 0,
+        finished: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(line) = state.next_line() {
+                match IndexEntry::parse_line(&line) {
+                    Some(entry) if state.matches(&entry) => return Some((Ok(entry), state)),
+                    _ => continue,
+                }
+            }
+
+            if state.finished {
+                return None;
+            }
+
+            match state.next_chunk().await {
+                Ok(Some(chunk)) => state.buffer.extend_from_slice(&chunk),
+                Ok(None) => {
+                    state.finished = true;
+                    // Terminate a final row that lacks a trailing newline.
+                    if !state.buffer.is_empty() {
+                        state.buffer.push(b'\n');
+                    }
+                }
+                Err(e) => {
+                    state.finished = true;
+                    state.buffer.clear();
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let entry = IndexEntry::parse_line(
+            "320193|Apple Inc.|8-K|2024-02-01|edgar/data/320193/0000320193-24-000006.txt\n",
+        )
+        .unwrap();
+
+        assert_eq!(entry.cik, 320193);
+        assert_eq!(entry.company_name, "Apple Inc.");
+        assert_eq!(entry.form_type, "8-K");
+        assert_eq!(
+            entry.date_filed,
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        );
+        assert_eq!(entry.filename, "edgar/data/320193/0000320193-24-000006.txt");
+    }
+
+    #[test]
+    fn test_parse_line_skips_header() {
+        assert!(IndexEntry::parse_line("CIK|Company Name|Form Type|Date Filed|Filename").is_none());
+        assert!(
+            IndexEntry::parse_line(
+                "--------------------------------------------------------------------------------"
+            )
+            .is_none()
+        );
+        assert!(
+            IndexEntry::parse_line(
+                "Description:           Master Index of EDGAR Dissemination Feed"
+            )
+            .is_none()
+        );
+    }
+}
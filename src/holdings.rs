@@ -0,0 +1,166 @@
+use crate::error::EDGARParserError;
+use serde::Deserialize;
+use serde_xml_rs::from_str as xml_from_str;
+use std::collections::BTreeSet;
+
+/// Represents one position from a Form 13F information table.
+///
+/// # Fields
+/// - `name_of_issuer`: Name of the issuer of the security.
+/// - `title_of_class`: Title of the class of the security, e.g. `COM`.
+/// - `cusip`: The security's nine-character CUSIP.
+/// - `value`: Market value of the position as reported.
+/// - `shares`: Number of shares or principal amount held.
+/// - `share_type`: `SH` for shares or `PRN` for principal amount.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Holding {
+    pub name_of_issuer: String,
+    #[serde(default)]
+    pub title_of_class: String,
+    pub cusip: String,
+    #[serde(default)]
+    pub value: f64,
+    #[serde(rename = "shrsOrPrnAmt", default)]
+    shares_or_principal: SharesOrPrincipal,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SharesOrPrincipal {
+    #[serde(default)]
+    ssh_prnamt: f64,
+    #[serde(default)]
+    ssh_prnamt_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct InformationTable {
+    #[serde(rename = "infoTable", default)]
+    info_table: Vec<Holding>,
+}
+
+impl Holding {
+    /// Returns the security's CUSIP.
+    pub fn cusip(&self) -> &str {
+        &self.cusip
+    }
+
+    /// Returns the number of shares or principal amount held.
+    pub fn shares(&self) -> f64 {
+        self.shares_or_principal.ssh_prnamt
+    }
+
+    /// Returns `SH` for shares or `PRN` for principal amount.
+    pub fn share_type(&self) -> &str {
+        &self.shares_or_principal.ssh_prnamt_type
+    }
+}
+
+/// Parses a Form 13F information table XML document into its holdings.
+///
+/// # Errors
+/// Returns `EDGARParserError::XMLParseError` if the document is not a valid information table.
+pub fn parse_information_table(xml: &str) -> Result<Vec<Holding>, EDGARParserError> {
+    let table: InformationTable = xml_from_str(xml)?;
+    Ok(table.info_table)
+}
+
+/// Cross-reference identifiers for an entity, for joining EDGAR data with other datasets.
+///
+/// # Fields
+/// - `cik`: The filer's Central Index Key (CIK) number.
+/// - `cusips`: Distinct CUSIPs found in the filing, sorted.
+/// - `leis`: Distinct Legal Entity Identifiers found in the filing, sorted.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Identifiers {
+    pub cik: u32,
+    pub cusips: Vec<String>,
+    pub leis: Vec<String>,
+}
+
+impl Identifiers {
+    /// Aggregates the distinct CUSIPs of a 13F filer's holdings. 13F information tables do not
+    /// carry LEIs, so `leis` is left empty.
+    pub fn from_holdings(cik: u32, holdings: &[Holding]) -> Self {
+        let cusips: BTreeSet<String> = holdings
+            .iter()
+            .map(|h| h.cusip().trim().to_uppercase())
+            .filter(|c| !c.is_empty())
+            .collect();
+
+        Self {
+            cik,
+            cusips: cusips.into_iter().collect(),
+            leis: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFORMATION_TABLE_FIXTURE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<informationTable xmlns="http://www.sec.gov/edgar/document/thirteenf/informationtable">
+  <infoTable>
+    <nameOfIssuer>APPLE INC</nameOfIssuer>
+    <titleOfClass>COM</titleOfClass>
+    <cusip>037833100</cusip>
+    <value>174347510</value>
+    <shrsOrPrnAmt>
+      <sshPrnamt>905560000</sshPrnamt>
+      <sshPrnamtType>SH</sshPrnamtType>
+    </shrsOrPrnAmt>
+    <investmentDiscretion>DFND</investmentDiscretion>
+  </infoTable>
+  <infoTable>
+    <nameOfIssuer>BANK AMER CORP</nameOfIssuer>
+    <titleOfClass>COM</titleOfClass>
+    <cusip>060505104</cusip>
+    <value>34586452</value>
+    <shrsOrPrnAmt>
+      <sshPrnamt>1032852006</sshPrnamt>
+      <sshPrnamtType>SH</sshPrnamtType>
+    </shrsOrPrnAmt>
+    <investmentDiscretion>DFND</investmentDiscretion>
+  </infoTable>
+  <infoTable>
+    <nameOfIssuer>APPLE INC</nameOfIssuer>
+    <titleOfClass>COM</titleOfClass>
+    <cusip>037833100</cusip>
+    <value>1000</value>
+    <shrsOrPrnAmt>
+      <sshPrnamt>5000</sshPrnamt>
+      <sshPrnamtType>SH</sshPrnamtType>
+    </shrsOrPrnAmt>
+    <investmentDiscretion>OTR</investmentDiscretion>
+  </infoTable>
+</informationTable>"#;
+
+    #[test]
+    fn test_parse_information_table() {
+        let holdings = parse_information_table(INFORMATION_TABLE_FIXTURE).unwrap();
+
+        assert_eq!(holdings.len(), 3);
+        assert_eq!(holdings[0].name_of_issuer, "APPLE INC");
+        assert_eq!(holdings[0].cusip(), "037833100");
+        assert_eq!(holdings[1].shares(), 1032852006.0);
+        assert_eq!(holdings[1].share_type(), "SH");
+    }
+
+    #[test]
+    fn test_identifiers_from_13f() {
+        let holdings = parse_information_table(INFORMATION_TABLE_FIXTURE).unwrap();
+        let identifiers = Identifiers::from_holdings(1067983, &holdings);
+
+        assert_eq!(
+            identifiers,
+            Identifiers {
+                cik: 1067983,
+                cusips: vec!["037833100".to_string(), "060505104".to_string()],
+                leis: Vec::new(),
+            }
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::holdings::Identifiers;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A best-effort mapping from Legal Entity Identifier (LEI) to CIK.
+///
+/// The SEC publishes no LEI to CIK file, so the mapping has to be assembled from filings that
+/// carry both, such as N-PORT and N-CEN reports, whose filer sections list the registrant's
+/// LEI next to its CIK. Build it from the `Identifiers` of parsed filings, or from a mapping
+/// you maintain yourself. Entities that never reported an LEI in a filing you have seen are
+/// absent. The index serializes with serde so it can be cached between runs.
+///
+/// # Fields
+/// - `ciks`: CIK for each LEI, keyed by the upper-cased LEI.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct LeiIndex {
+    pub ciks: HashMap<String, u32>,
+}
+
+impl LeiIndex {
+    /// Builds an index from `(lei, cik)` pairs, e.g. a user-provided mapping. A later pair
+    /// replaces an earlier one for the same LEI.
+    pub fn from_pairs<I, S>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: AsRef<str>,
+    {
+        let mut index = Self::default();
+        for (lei, cik) in pairs {
+            index.insert(lei.as_ref(), cik);
+        }
+        index
+    }
+
+    /// Builds an index from the identifiers of parsed filings, mapping each LEI to the CIK of
+    /// the entity it was reported for.
+    pub fn from_identifiers(identifiers: &[Identifiers]) -> Self {
+        Self::from_pairs(
+            identifiers
+                .iter()
+                .flat_map(|ids| ids.leis.iter().map(move |lei| (lei, ids.cik))),
+        )
+    }
+
+    /// Records that `lei` belongs to `cik`. Blank LEIs are ignored.
+    pub fn insert(&mut self, lei: &str, cik: u32) {
+        let lei = normalize_lei(lei);
+        if !lei.is_empty() {
+            self.ciks.insert(lei, cik);
+        }
+    }
+
+    /// Returns the CIK recorded for `lei`, ignoring case and surrounding whitespace, or `None`
+    /// if the LEI has not been seen.
+    pub fn resolve_by_lei(&self, lei: &str) -> Option<u32> {
+        self.ciks.get(&normalize_lei(lei)).copied()
+    }
+
+    /// Returns the number of LEIs in the index.
+    pub fn len(&self) -> usize {
+        self.ciks.len()
+    }
+
+    /// Returns `true` if the index holds no LEIs.
+    pub fn is_empty(&self) -> bool {
+        self.ciks.is_empty()
+    }
+}
+
+/// Upper-cases and trims an LEI, the 20-character alphanumeric form GLEIF publishes.
+fn normalize_lei(lei: &str) -> String {
+    lei.trim().to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_by_lei() {
+        let index = LeiIndex::from_pairs([
+            ("HWUPKR0MPOU8FGXBT394", 320193),
+            ("549300DRQQI75D2JP341", 1067983),
+        ]);
+
+        assert_eq!(index.resolve_by_lei("HWUPKR0MPOU8FGXBT394"), Some(320193));
+        assert_eq!(
+            index.resolve_by_lei(" 549300drqqi75d2jp341 "),
+            Some(1067983)
+        );
+        assert_eq!(index.resolve_by_lei("5493000000000000000X"), None);
+        assert_eq!(index.len(), 2);
+    }
+
+    #[test]
+    fn test_from_identifiers() {
+        let index = LeiIndex::from_identifiers(&[
+            Identifiers {
+                cik: 36405,
+                cusips: Vec::new(),
+                leis: vec!["549300UC06B3WD3SFN80".to_string()],
+            },
+            Identifiers {
+                cik: 1067983,
+                cusips: vec!["037833100".to_string()],
+                leis: Vec::new(),
+            },
+        ]);
+
+        assert_eq!(index.resolve_by_lei("549300UC06B3WD3SFN80"), Some(36405));
+        assert_eq!(index.len(), 1);
+    }
+}
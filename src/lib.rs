@@ -1,6 +1,57 @@
 mod api;
+mod atom;
+mod company_facts;
+mod config;
+mod document;
+mod download;
 mod edgar;
 mod error;
+mod filing_index;
 mod filing_type_builder;
-pub use edgar::EdgarParser;
-pub use filing_type_builder::EdgarFilingQueryBuilder;
\ No newline at end of file
+mod frames;
+mod full_index;
+mod holdings;
+mod lei;
+mod ownership;
+mod rate_limit;
+mod sic;
+mod submissions;
+mod ticker_index;
+mod user_agent;
+mod watchlist;
+pub use api::{
+    fetch_filing_text, fetch_filing_text_with_config, fetch_http_body, fetch_http_body_with_config,
+    fetch_http_body_with_retry, fetch_http_body_with_timeout, fetch_with_headers,
+    fetch_with_headers_with_config,
+};
+pub use atom::{FilingEntry, parse_atom_feed};
+pub use company_facts::{
+    CompanyFacts, Concept, ConceptRecord, FactPoint, FinancialSummary, PeriodLength, Restatement,
+};
+pub use config::{ClientConfig, EdgarConfig};
+pub use document::html_to_text;
+pub use download::{DownloadLayout, DownloadReport};
+pub use edgar::{CompanyData, EdgarParser};
+pub use filing_index::{FilingDirectory, FilingIndex, FilingIndexItem};
+pub use filing_type_builder::{
+    Action, EdgarFilingQueryBuilder, FilingCategory, FilingTypeOption, OwnerOption,
+    OwnerPerspective,
+};
+pub use frames::{FrameDatum, FrameHeader, Period, Taxonomy, XbrlFrame, frames_url, stream_frames};
+pub use full_index::IndexEntry;
+pub use holdings::{Holding, Identifiers, parse_information_table};
+pub use lei::LeiIndex;
+pub use ownership::{Transaction, parse_ownership_document};
+pub use rate_limit::{
+    DEFAULT_RATE_LIMIT, is_throttling, is_throttling_with_config, rate_limit, set_rate_limit,
+};
+pub use sic::sic_description;
+pub use submissions::{
+    Address, Addresses, Filing, FormType, FormerName, RecentFilings, SubmissionFilings,
+    Submissions, XbrlKind,
+};
+pub use ticker_index::{
+    CompactCompany, CompactTickerIndex, TickerChange, TickerDelta, TickerIndex,
+};
+pub use user_agent::UserAgent;
+pub use watchlist::WatchlistReport;
@@ -0,0 +1,352 @@
+use crate::error::EDGARParserError;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+use serde_xml_rs::from_str as xml_from_str;
+
+/// Represents one transaction reported on a Form 3, 4 or 5 ownership document.
+///
+/// # Fields
+/// - `owner_name`: Name of the reporting owner (the insider), e.g. `LEVINSON ARTHUR D`.
+/// - `owner_cik`: The reporting owner's CIK as reported, zero-padded.
+/// - `security_title`: Title of the security traded, e.g. `Common Stock`.
+/// - `transaction_date`: Date of the transaction, if it parses as `YYYY-MM-DD`.
+/// - `code`: The SEC transaction code, e.g. `P` (open market purchase), `S` (sale) or
+///   `M` (option exercise).
+/// - `shares`: Number of shares or units involved.
+/// - `price_per_share`: Price per share. `None` when only a footnote is given.
+/// - `acquired_disposed`: `A` if the shares were acquired, `D` if disposed of.
+/// - `shares_owned_after`: Shares held after the transaction, if reported.
+/// - `derivative`: Whether the transaction is in a derivative security such as an option.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Transaction {
+    pub owner_name: String,
+    pub owner_cik: String,
+    pub security_title: String,
+    pub transaction_date: Option<NaiveDate>,
+    pub code: String,
+    pub shares: f64,
+    pub price_per_share: Option<f64>,
+    pub acquired_disposed: String,
+    pub shares_owned_after: Option<f64>,
+    pub derivative: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnershipDocument {
+    #[serde(default)]
+    reporting_owner: Vec<ReportingOwner>,
+    #[serde(default)]
+    non_derivative_table: Option<OwnershipTable>,
+    #[serde(default)]
+    derivative_table: Option<OwnershipTable>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingOwner {
+    reporting_owner_id: ReportingOwnerId,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingOwnerId {
+    #[serde(default)]
+    rpt_owner_cik: String,
+    #[serde(default)]
+    rpt_owner_name: String,
+}
+
+// Transactions and holdings may be interleaved within a table, so its rows are read as one
+// sequence of choices rather than as separate repeated fields.
+#[derive(Debug, Deserialize)]
+struct OwnershipTable {
+    #[serde(rename = "#content", default)]
+    rows: Vec<TableRow>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum TableRow {
+    NonDerivativeTransaction(RawTransaction),
+    DerivativeTransaction(RawTransaction),
+    NonDerivativeHolding(IgnoredAny),
+    DerivativeHolding(IgnoredAny),
+}
+
+impl OwnershipTable {
+    fn into_transactions(self) -> impl Iterator<Item = RawTransaction> {
+        self.rows.into_iter().filter_map(|row| match row {
+            TableRow::NonDerivativeTransaction(t) | TableRow::DerivativeTransaction(t) => Some(t),
+            TableRow::NonDerivativeHolding(_) | TableRow::DerivativeHolding(_) => None,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTransaction {
+    #[serde(default)]
+    security_title: Value,
+    #[serde(default)]
+    transaction_date: Value,
+    #[serde(default)]
+    transaction_coding: TransactionCoding,
+    #[serde(default)]
+    transaction_amounts: TransactionAmounts,
+    #[serde(default)]
+    post_transaction_amounts: PostTransactionAmounts,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionCoding {
+    #[serde(default)]
+    transaction_code: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionAmounts {
+    #[serde(default)]
+    transaction_shares: Value,
+    #[serde(default)]
+    transaction_price_per_share: Value,
+    #[serde(default)]
+    transaction_acquired_disposed_code: Value,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostTransactionAmounts {
+    #[serde(default)]
+    shares_owned_following_transaction: Value,
+}
+
+// Ownership documents wrap most data points in `<value>`, next to optional footnote references.
+#[derive(Debug, Default, Deserialize)]
+struct Value {
+    #[serde(default)]
+    value: Option<String>,
+}
+
+impl Value {
+    fn text(&self) -> String {
+        self.value.as_deref().unwrap_or_default().trim().to_string()
+    }
+
+    fn number(&self) -> Option<f64> {
+        self.value.as_deref()?.trim().parse().ok()
+    }
+}
+
+/// Returns the path of the raw ownership XML for a filing's `primary_document`. The submissions
+/// JSON lists ownership forms by their XSL-rendered HTML view, e.g.
+/// `xslF345X05/wk-form4_1712345678.xml`, while the XML itself sits at the top of the filing.
+pub(crate) fn raw_ownership_document(primary_document: &str) -> &str {
+    match primary_document.split_once('/') {
+        Some((folder, document)) if folder.starts_with("xsl") => document,
+        _ => primary_document,
+    }
+}
+
+/// Parses a Form 3, 4 or 5 ownership XML document into its non-derivative and derivative
+/// transactions, in document order. Holdings reported without a transaction are skipped.
+/// Documents with several reporting owners attribute each transaction to the first.
+///
+/// # Errors
+/// Returns `EDGARParserError::XMLParseError` if the document is not a valid ownership document.
+pub fn parse_ownership_document(xml: &str) -> Result<Vec<Transaction>, EDGARParserError> {
+    let document: OwnershipDocument = xml_from_str(xml)?;
+
+    let (owner_name, owner_cik) = document
+        .reporting_owner
+        .first()
+        .map(|o| {
+            (
+                o.reporting_owner_id.rpt_owner_name.trim().to_string(),
+                o.reporting_owner_id.rpt_owner_cik.trim().to_string(),
+            )
+        })
+        .unwrap_or_default();
+
+    let non_derivative = document
+        .non_derivative_table
+        .into_iter()
+        .flat_map(OwnershipTable::into_transactions)
+        .map(|t| (t, false));
+    let derivative = document
+        .derivative_table
+        .into_iter()
+        .flat_map(OwnershipTable::into_transactions)
+        .map(|t| (t, true));
+
+    Ok(non_derivative
+        .chain(derivative)
+        .map(|(raw, derivative)| Transaction {
+            owner_name: owner_name.clone(),
+            owner_cik: owner_cik.clone(),
+            security_title: raw.security_title.text(),
+            transaction_date: NaiveDate::parse_from_str(&raw.transaction_date.text(), "%Y-%m-%d")
+                .ok(),
+            code: raw.transaction_coding.transaction_code.trim().to_string(),
+            shares: raw
+                .transaction_amounts
+                .transaction_shares
+                .number()
+                .unwrap_or_default(),
+            price_per_share: raw.transaction_amounts.transaction_price_per_share.number(),
+            acquired_disposed: raw
+                .transaction_amounts
+                .transaction_acquired_disposed_code
+                .text(),
+            shares_owned_after: raw
+                .post_transaction_amounts
+                .shares_owned_following_transaction
+                .number(),
+            derivative,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORM_4_FIXTURE: &str = r#"<?xml version="1.0"?>
+<ownershipDocument>
+    <schemaVersion>X0508</schemaVersion>
+    <documentType>4</documentType>
+    <periodOfReport>2024-04-01</periodOfReport>
+    <issuer>
+        <issuerCik>0000320193</issuerCik>
+        <issuerName>Apple Inc.</issuerName>
+        <issuerTradingSymbol>AAPL</issuerTradingSymbol>
+    </issuer>
+    <reportingOwner>
+        <reportingOwnerId>
+            <rptOwnerCik>0001214128</rptOwnerCik>
+            <rptOwnerName>LEVINSON ARTHUR D</rptOwnerName>
+        </reportingOwnerId>
+        <reportingOwnerRelationship>
+            <isDirector>1</isDirector>
+        </reportingOwnerRelationship>
+    </reportingOwner>
+    <nonDerivativeTable>
+        <nonDerivativeTransaction>
+            <securityTitle><value>Common Stock</value></securityTitle>
+            <transactionDate><value>2024-04-01</value></transactionDate>
+            <transactionCoding>
+                <transactionFormType>4</transactionFormType>
+                <transactionCode>S</transactionCode>
+                <equitySwapInvolved>0</equitySwapInvolved>
+            </transactionCoding>
+            <transactionAmounts>
+                <transactionShares><value>1000</value></transactionShares>
+                <transactionPricePerShare><value>170.5</value><footnoteId id="F1"/></transactionPricePerShare>
+                <transactionAcquiredDisposedCode><value>D</value></transactionAcquiredDisposedCode>
+            </transactionAmounts>
+            <postTransactionAmounts>
+                <sharesOwnedFollowingTransaction><value>4000</value></sharesOwnedFollowingTransaction>
+            </postTransactionAmounts>
+            <ownershipNature>
+                <directOrIndirectOwnership><value>D</value></directOrIndirectOwnership>
+            </ownershipNature>
+        </nonDerivativeTransaction>
+    </nonDerivativeTable>
+    <derivativeTable>
+        <derivativeTransaction>
+            <securityTitle><value>Restricted Stock Unit</value></securityTitle>
+            <transactionDate><value>2024-04-01</value></transactionDate>
+            <transactionCoding>
+                <transactionFormType>4</transactionFormType>
+                <transactionCode>M</transactionCode>
+            </transactionCoding>
+            <transactionAmounts>
+                <transactionShares><value>500</value></transactionShares>
+                <transactionPricePerShare><footnoteId id="F2"/></transactionPricePerShare>
+                <transactionAcquiredDisposedCode><value>D</value></transactionAcquiredDisposedCode>
+            </transactionAmounts>
+            <postTransactionAmounts>
+                <sharesOwnedFollowingTransaction><value>0</value></sharesOwnedFollowingTransaction>
+            </postTransactionAmounts>
+        </derivativeTransaction>
+    </derivativeTable>
+</ownershipDocument>"#;
+
+    #[test]
+    fn test_parse_ownership_document() {
+        let transactions = parse_ownership_document(FORM_4_FIXTURE).unwrap();
+
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(
+            transactions[0],
+            Transaction {
+                owner_name: "LEVINSON ARTHUR D".to_string(),
+                owner_cik: "0001214128".to_string(),
+                security_title: "Common Stock".to_string(),
+                transaction_date: NaiveDate::from_ymd_opt(2024, 4, 1),
+                code: "S".to_string(),
+                shares: 1000.0,
+                price_per_share: Some(170.5),
+                acquired_disposed: "D".to_string(),
+                shares_owned_after: Some(4000.0),
+                derivative: false,
+            }
+        );
+        assert!(transactions[1].derivative);
+        assert_eq!(transactions[1].code, "M");
+        assert_eq!(transactions[1].price_per_share, None);
+    }
+
+    #[test]
+    fn test_parse_ownership_document_skips_holdings() {
+        let xml = r#"<ownershipDocument>
+            <reportingOwner>
+                <reportingOwnerId><rptOwnerCik>0001214128</rptOwnerCik><rptOwnerName>LEVINSON ARTHUR D</rptOwnerName></reportingOwnerId>
+            </reportingOwner>
+            <nonDerivativeTable>
+                <nonDerivativeTransaction>
+                    <transactionCoding><transactionCode>P</transactionCode></transactionCoding>
+                    <transactionAmounts><transactionShares><value>10</value></transactionShares></transactionAmounts>
+                </nonDerivativeTransaction>
+                <nonDerivativeHolding>
+                    <securityTitle><value>Common Stock</value></securityTitle>
+                </nonDerivativeHolding>
+                <nonDerivativeTransaction>
+                    <transactionCoding><transactionCode>S</transactionCode></transactionCoding>
+                    <transactionAmounts><transactionShares><value>20</value></transactionShares></transactionAmounts>
+                </nonDerivativeTransaction>
+            </nonDerivativeTable>
+        </ownershipDocument>"#;
+
+        let codes: Vec<String> = parse_ownership_document(xml)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.code)
+            .collect();
+        assert_eq!(codes, vec!["P", "S"]);
+    }
+
+    #[test]
+    fn test_raw_ownership_document() {
+        assert_eq!(
+            raw_ownership_document("xslF345X05/wk-form4_1712345678.xml"),
+            "wk-form4_1712345678.xml"
+        );
+        assert_eq!(raw_ownership_document("form4.xml"), "form4.xml");
+    }
+
+    #[test]
+    fn test_parse_ownership_document_without_tables() {
+        let xml = r#"<ownershipDocument>
+            <documentType>3</documentType>
+            <reportingOwner>
+                <reportingOwnerId><rptOwnerCik>0001214128</rptOwnerCik><rptOwnerName>LEVINSON ARTHUR D</rptOwnerName></reportingOwnerId>
+            </reportingOwner>
+        </ownershipDocument>"#;
+
+        assert!(parse_ownership_document(xml).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+use crate::config::EdgarConfig;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Requests per second allowed by default, the SEC's published fair-access limit.
+pub const DEFAULT_RATE_LIMIT: u32 = 10;
+
+/// Requests per second currently allowed; `0` disables rate limiting.
+static RATE_LIMIT: AtomicU32 = AtomicU32::new(DEFAULT_RATE_LIMIT);
+
+/// Request tokens shared by every request the process makes, since the SEC counts requests
+/// per client rather than per `EdgarConfig`.
+static BUCKET: Mutex<TokenBucket> = Mutex::const_new(TokenBucket {
+    tokens: 0.0,
+    refilled_at: None,
+});
+
+/// Spacing shared by every request sent with a config that has a `min_request_interval`.
+static LAST_REQUEST: Mutex<RequestSpacing> = Mutex::const_new(RequestSpacing { sent_at: None });
+
+/// A token bucket holding up to one second's worth of requests. A full bucket lets a short
+/// burst through immediately; after that requests are spaced at the configured rate.
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Option<Instant>,
+}
+
+/// The time the last spaced request was sent, for keeping a minimum interval between requests.
+struct RequestSpacing {
+    sent_at: Option<Instant>,
+}
+
+/// A source of time for the rate limiter, so tests can advance time without sleeping.
+pub(crate) trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+
+    /// Waits for `duration` to pass.
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send;
+}
+
+/// The wall clock, sleeping on the Tokio timer.
+pub(crate) struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(duration)
+    }
+}
+
+impl TokenBucket {
+    /// Returns the tokens the bucket holds at `now` at `rate` requests per second. A bucket
+    /// that has not been used yet is full.
+    fn available(&self, rate: u32, now: Instant) -> f64 {
+        let capacity = f64::from(rate);
+        match self.refilled_at {
+            Some(refilled_at) => {
+                (self.tokens + (now - refilled_at).as_secs_f64() * capacity).min(capacity)
+            }
+            None => capacity,
+        }
+    }
+
+    /// Returns `true` if a request at `now` would have to wait for a token.
+    fn would_wait(&self, rate: u32, now: Instant) -> bool {
+        self.available(rate, now) < 1.0
+    }
+
+    /// Refills the bucket for the time passed since the last request at `rate` requests per
+    /// second, waits on `clock` until a token is available, and takes it.
+    async fn take(&mut self, rate: u32, clock: &(impl Clock + Sync)) {
+        let capacity = f64::from(rate);
+        let now = clock.now();
+        self.tokens = self.available(rate, now);
+        self.refilled_at = Some(now);
+
+        if self.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - self.tokens) / capacity);
+            clock.sleep(wait).await;
+            self.tokens = 1.0;
+            self.refilled_at = Some(clock.now());
+        }
+        self.tokens -= 1.0;
+    }
+}
+
+impl RequestSpacing {
+    /// Returns `true` if a request at `now` would have to wait for `interval` to pass since the
+    /// previous one.
+    fn would_wait(&self, interval: Duration, now: Instant) -> bool {
+        self.sent_at
+            .is_some_and(|previous| now < previous + interval)
+    }
+
+    /// Waits on `clock` until `interval` has passed since the previous request, then records
+    /// the current time for the next one.
+    async fn wait(&mut self, interval: Duration, clock: &(impl Clock + Sync)) {
+        if let Some(previous) = self.sent_at {
+            let elapsed = clock.now().saturating_duration_since(previous);
+            if elapsed < interval {
+                clock.sleep(interval - elapsed).await;
+            }
+        }
+        self.sent_at = Some(clock.now());
+    }
+}
+
+/// Sets the maximum number of requests per second the crate sends, process-wide. The
+/// default is 10, the SEC's limit; `0` disables rate limiting.
+pub fn set_rate_limit(per_second: u32) {
+    RATE_LIMIT.store(per_second, Ordering::Relaxed);
+}
+
+/// Returns the maximum number of requests per second currently allowed.
+pub fn rate_limit() -> u32 {
+    RATE_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Returns `true` if the next request would have to wait for the rate limit, e.g. to show a
+/// spinner. A request already waiting for a token counts as throttling. This only checks the
+/// process-wide rate limit; use `is_throttling_with_config` to also account for a config's
+/// `min_request_interval`.
+pub fn is_throttling() -> bool {
+    is_throttling_with_config(&EdgarConfig::default())
+}
+
+/// Returns `true` if the next request made with `config` would have to wait, either for the
+/// rate limit or for its `min_request_interval` since the previous spaced request.
+pub fn is_throttling_with_config(config: &EdgarConfig) -> bool {
+    let now = TokioClock.now();
+
+    let bucket_waits = match rate_limit() {
+        0 => false,
+        rate => BUCKET
+            .try_lock()
+            .map_or(true, |bucket| bucket.would_wait(rate, now)),
+    };
+    let interval_waits = config.min_request_interval.is_some_and(|interval| {
+        LAST_REQUEST
+            .try_lock()
+            .map_or(true, |spacing| spacing.would_wait(interval, now))
+    });
+
+    bucket_waits || interval_waits
+}
+
+/// Waits until the rate limit allows another request and takes a token for it. Callers
+/// queue on the bucket, so waiting requests are released one at a time.
+pub(crate) async fn acquire() {
+    let rate = rate_limit();
+    if rate == 0 {
+        return;
+    }
+    BUCKET.lock().await.take(rate, &TokioClock).await;
+}
+
+/// Waits until `interval` has passed since the previous request spaced this way, then records
+/// the current time for the next one. Callers queue on the lock, so concurrent requests are
+/// sent one after another.
+pub(crate) async fn space_request(interval: Duration) {
+    LAST_REQUEST.lock().await.wait(interval, &TokioClock).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex as StdMutex;
+
+    /// A clock that only moves when slept on or advanced.
+    struct FakeClock {
+        now: StdMutex<Instant>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self {
+                now: StdMutex::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+            self.advance(duration);
+            std::future::ready(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket_throttles_after_burst() {
+        let clock = FakeClock::new();
+        let start = clock.now();
+        let mut bucket = TokenBucket {
+            tokens: 0.0,
+            refilled_at: None,
+        };
+
+        // A full bucket lets one second's worth of requests through at once.
+        for _ in 0..10 {
+            bucket.take(10, &clock).await;
+        }
+        assert_eq!(clock.now(), start);
+
+        // The next request waits for a token to refill.
+        bucket.take(10, &clock).await;
+        assert_eq!(clock.now() - start, Duration::from_millis(100));
+
+        // After 250ms idle, two and a half tokens are available.
+        clock.advance(Duration::from_millis(250));
+        let idle_end = clock.now();
+        bucket.take(10, &clock).await;
+        bucket.take(10, &clock).await;
+        assert_eq!(clock.now(), idle_end);
+        bucket.take(10, &clock).await;
+        let waited = clock.now() - idle_end;
+        assert!(waited.abs_diff(Duration::from_millis(50)) < Duration::from_micros(1));
+    }
+
+    #[tokio::test]
+    async fn test_would_wait_until_refilled() {
+        let clock = FakeClock::new();
+        let mut bucket = TokenBucket {
+            tokens: 0.0,
+            refilled_at: None,
+        };
+        assert!(!bucket.would_wait(10, clock.now()));
+
+        for _ in 0..10 {
+            bucket.take(10, &clock).await;
+        }
+        assert!(bucket.would_wait(10, clock.now()));
+
+        clock.advance(Duration::from_millis(100));
+        assert!(!bucket.would_wait(10, clock.now()));
+    }
+
+    #[tokio::test]
+    async fn test_request_spacing_waits_for_interval() {
+        let clock = FakeClock::new();
+        let start = clock.now();
+        let interval = Duration::from_millis(300);
+        let mut spacing = RequestSpacing { sent_at: None };
+
+        // The first request goes out at once; the next waits out the interval.
+        spacing.wait(interval, &clock).await;
+        assert_eq!(clock.now(), start);
+        assert!(spacing.would_wait(interval, clock.now()));
+        spacing.wait(interval, &clock).await;
+        assert_eq!(clock.now() - start, interval);
+
+        // Time already passed since the previous request counts toward the interval.
+        clock.advance(Duration::from_millis(200));
+        spacing.wait(interval, &clock).await;
+        assert_eq!(clock.now() - start, Duration::from_millis(600));
+
+        clock.advance(interval);
+        assert!(!spacing.would_wait(interval, clock.now()));
+    }
+
+    #[tokio::test]
+    async fn test_is_throttling_after_draining_bucket() {
+        // Other tests share the bucket, so take a full second's worth of tokens to be sure
+        // none are left, then check before the next one refills.
+        for _ in 0..rate_limit() {
+            acquire().await;
+        }
+
+        assert!(is_throttling());
+    }
+}
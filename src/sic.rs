@@ -0,0 +1,471 @@
+use phf::{Map, phf_map};
+
+// Static map of SIC code -> industry title, as published in the SEC's SIC code list
+// (https://www.sec.gov/search-filings/standard-industrial-classification-sic-code-list).
+static SIC_DESCRIPTION_MAP: Map<u32, &'static str> = phf_map! {
+    100u32 => "AGRICULTURAL PRODUCTION-CROPS",
+    200u32 => "AGRICULTURAL PROD-LIVESTOCK & ANIMAL SPECIALTIES",
+    700u32 => "AGRICULTURAL SERVICES",
+    800u32 => "FORESTRY",
+    900u32 => "FISHING, HUNTING AND TRAPPING",
+    1000u32 => "METAL MINING",
+    1040u32 => "GOLD AND SILVER ORES",
+    1090u32 => "MISCELLANEOUS METAL ORES",
+    1220u32 => "BITUMINOUS COAL & LIGNITE MINING",
+    1221u32 => "BITUMINOUS COAL & LIGNITE SURFACE MINING",
+    1311u32 => "CRUDE PETROLEUM & NATURAL GAS",
+    1381u32 => "DRILLING OIL & GAS WELLS",
+    1382u32 => "OIL & GAS FIELD EXPLORATION SERVICES",
+    1389u32 => "OIL & GAS FIELD SERVICES, NEC",
+    1400u32 => "MINING & QUARRYING OF NONMETALLIC MINERALS (NO FUELS)",
+    1520u32 => "GENERAL BLDG CONTRACTORS - RESIDENTIAL BLDGS",
+    1531u32 => "OPERATIVE BUILDERS",
+    1540u32 => "GENERAL BLDG CONTRACTORS - NONRESIDENTIAL BLDGS",
+    1600u32 => "HEAVY CONSTRUCTION OTHER THAN BLDG CONST - CONTRACTORS",
+    1623u32 => "WATER, SEWER, PIPELINE, COMM & POWER LINE CONSTRUCTION",
+    1700u32 => "CONSTRUCTION - SPECIAL TRADE CONTRACTORS",
+    1731u32 => "ELECTRICAL WORK",
+    2000u32 => "FOOD AND KINDRED PRODUCTS",
+    2011u32 => "MEAT PACKING PLANTS",
+    2013u32 => "SAUSAGES & OTHER PREPARED MEAT PRODUCTS",
+    2015u32 => "POULTRY SLAUGHTERING AND PROCESSING",
+    2020u32 => "DAIRY PRODUCTS",
+    2024u32 => "ICE CREAM & FROZEN DESSERTS",
+    2030u32 => "CANNED, FROZEN & PRESERVD FRUIT, VEG & FOOD SPECIALTIES",
+    2033u32 => "CANNED, FRUITS, VEG, PRESERVES, JAMS & JELLIES",
+    2040u32 => "GRAIN MILL PRODUCTS",
+    2050u32 => "BAKERY PRODUCTS",
+    2052u32 => "COOKIES & CRACKERS",
+    2060u32 => "SUGAR & CONFECTIONERY PRODUCTS",
+    2070u32 => "FATS & OILS",
+    2080u32 => "BEVERAGES",
+    2082u32 => "MALT BEVERAGES",
+    2086u32 => "BOTTLED & CANNED SOFT DRINKS & CARBONATED WATERS",
+    2090u32 => "MISCELLANEOUS FOOD PREPARATIONS & KINDRED PRODUCTS",
+    2092u32 => "PREPARED FRESH OR FROZEN FISH & SEAFOODS",
+    2100u32 => "TOBACCO PRODUCTS",
+    2111u32 => "CIGARETTES",
+    2200u32 => "TEXTILE MILL PRODUCTS",
+    2211u32 => "BROADWOVEN FABRIC MILLS, COTTON",
+    2221u32 => "BROADWOVEN FABRIC MILLS, MAN MADE FIBER & SILK",
+    2250u32 => "KNITTING MILLS",
+    2253u32 => "KNIT OUTERWEAR MILLS",
+    2273u32 => "CARPETS & RUGS",
+    2300u32 => "APPAREL & OTHER FINISHD PRODS OF FABRICS & SIMILAR MATL",
+    2320u32 => "MEN'S & BOYS' FURNISHGS, WORK CLOTHG, & ALLIED GARMENTS",
+    2330u32 => "WOMEN'S, MISSES', AND JUNIORS OUTERWEAR",
+    2340u32 => "WOMEN'S, MISSES', CHILDREN'S & INFANTS' UNDERGARMENTS",
+    2390u32 => "MISCELLANEOUS FABRICATED TEXTILE PRODUCTS",
+    2400u32 => "LUMBER & WOOD PRODUCTS (NO FURNITURE)",
+    2421u32 => "SAWMILLS & PLANTING MILLS, GENERAL",
+    2430u32 => "MILLWOOD, VENEER, PLYWOOD, & STRUCTURAL WOOD MEMBERS",
+    2451u32 => "MOBILE HOMES",
+    2452u32 => "PREFABRICATED WOOD BLDGS & COMPONENTS",
+    2510u32 => "HOUSEHOLD FURNITURE",
+    2511u32 => "WOOD HOUSEHOLD FURNITURE, (NO UPHOLSTERED)",
+    2520u32 => "OFFICE FURNITURE",
+    2522u32 => "OFFICE FURNITURE (NO WOOD)",
+    2531u32 => "PUBLIC BLDG & RELATED FURNITURE",
+    2540u32 => "PARTITIONS, SHELVG, LOCKERS, & OFFICE & STORE FIXTURES",
+    2590u32 => "MISCELLANEOUS FURNITURE & FIXTURES",
+    2600u32 => "PAPERS & ALLIED PRODUCTS",
+    2611u32 => "PULP MILLS",
+    2621u32 => "PAPER MILLS",
+    2631u32 => "PAPERBOARD MILLS",
+    2650u32 => "PAPERBOARD CONTAINERS & BOXES",
+    2670u32 => "CONVERTED PAPER & PAPERBOARD PRODS (NO CONTANERS/BOXES)",
+    2673u32 => "PLASTICS, FOIL & COATED PAPER BAGS",
+    2711u32 => "NEWSPAPERS: PUBLISHING OR PUBLISHING & PRINTING",
+    2721u32 => "PERIODICALS: PUBLISHING OR PUBLISHING & PRINTING",
+    2731u32 => "BOOKS: PUBLISHING OR PUBLISHING & PRINTING",
+    2732u32 => "BOOK PRINTING",
+    2741u32 => "MISCELLANEOUS PUBLISHING",
+    2750u32 => "COMMERCIAL PRINTING",
+    2761u32 => "MANIFOLD BUSINESS FORMS",
+    2771u32 => "GREETING CARDS",
+    2780u32 => "BLANKBOOKS, LOOSELEAF BINDERS & BOOKBINDG & RELATD WORK",
+    2790u32 => "SERVICE INDUSTRIES FOR THE PRINTING TRADE",
+    2800u32 => "CHEMICALS & ALLIED PRODUCTS",
+    2810u32 => "INDUSTRIAL INORGANIC CHEMICALS",
+    2820u32 => "PLASTIC MATERIAL, SYNTH RESIN/RUBBER, CELLULOS (NO GLASS)",
+    2821u32 => "PLASTIC MATERIALS, SYNTH RESINS & NONVULCAN ELASTOMERS",
+    2833u32 => "MEDICINAL CHEMICALS & BOTANICAL PRODUCTS",
+    2834u32 => "PHARMACEUTICAL PREPARATIONS",
+    2835u32 => "IN VITRO & IN VIVO DIAGNOSTIC SUBSTANCES",
+    2836u32 => "BIOLOGICAL PRODUCTS, (NO DIAGNOSTIC SUBSTANCES)",
+    2840u32 => "SOAP, DETERGENTS, CLEANG PREPARATIONS, PERFUMES, COSMETICS",
+    2842u32 => "SPECIALTY CLEANING, POLISHING AND SANITATION PREPARATIONS",
+    2844u32 => "PERFUMES, COSMETICS & OTHER TOILET PREPARATIONS",
+    2851u32 => "PAINTS, VARNISHES, LACQUERS, ENAMELS & ALLIED PRODS",
+    2860u32 => "INDUSTRIAL ORGANIC CHEMICALS",
+    2870u32 => "AGRICULTURAL CHEMICALS",
+    2890u32 => "MISCELLANEOUS CHEMICAL PRODUCTS",
+    2891u32 => "ADHESIVES & SEALANTS",
+    2911u32 => "PETROLEUM REFINING",
+    2950u32 => "ASPHALT PAVING & ROOFING MATERIALS",
+    2990u32 => "MISCELLANEOUS PRODUCTS OF PETROLEUM & COAL",
+    3011u32 => "TIRES & INNER TUBES",
+    3021u32 => "RUBBER & PLASTICS FOOTWEAR",
+    3050u32 => "GASKETS, PACKG & SEALG DEVICES & RUBBER & PLASTICS HOSE",
+    3060u32 => "FABRICATED RUBBER PRODUCTS, NEC",
+    3080u32 => "MISCELLANEOUS PLASTICS PRODUCTS",
+    3081u32 => "UNSUPPORTED PLASTICS FILM & SHEET",
+    3086u32 => "PLASTICS FOAM PRODUCTS",
+    3089u32 => "PLASTICS PRODUCTS, NEC",
+    3100u32 => "LEATHER & LEATHER PRODUCTS",
+    3140u32 => "FOOTWEAR, (NO RUBBER)",
+    3211u32 => "FLAT GLASS",
+    3220u32 => "GLASS & GLASSWARE, PRESSED OR BLOWN",
+    3221u32 => "GLASS CONTAINERS",
+    3231u32 => "GLASS PRODUCTS, MADE OF PURCHASED GLASS",
+    3241u32 => "CEMENT, HYDRAULIC",
+    3250u32 => "STRUCTURAL CLAY PRODUCTS",
+    3260u32 => "POTTERY & RELATED PRODUCTS",
+    3270u32 => "CONCRETE, GYPSUM & PLASTER PRODUCTS",
+    3272u32 => "CONCRETE PRODUCTS, EXCEPT BLOCK & BRICK",
+    3281u32 => "CUT STONE & STONE PRODUCTS",
+    3290u32 => "ABRASIVE, ASBESTOS & MISC NONMETALLIC MINERAL PRODS",
+    3310u32 => "STEEL WORKS, BLAST FURNACES & ROLLING & FINISHING MILLS",
+    3312u32 => "STEEL WORKS, BLAST FURNACES & ROLLING MILLS (COKE OVENS)",
+    3317u32 => "STEEL PIPE & TUBES",
+    3320u32 => "IRON & STEEL FOUNDRIES",
+    3330u32 => "PRIMARY SMELTING & REFINING OF NONFERROUS METALS",
+    3334u32 => "PRIMARY PRODUCTION OF ALUMINUM",
+    3341u32 => "SECONDARY SMELTING & REFINING OF NONFERROUS METALS",
+    3350u32 => "ROLLING DRAWING & EXTRUDING OF NONFERROUS METALS",
+    3357u32 => "DRAWING & INSULATING OF NONFERROUS WIRE",
+    3360u32 => "NONFERROUS FOUNDRIES (CASTINGS)",
+    3390u32 => "MISCELLANEOUS PRIMARY METAL PRODUCTS",
+    3411u32 => "METAL CANS",
+    3412u32 => "METAL SHIPPING BARRELS, DRUMS, KEGS & PAILS",
+    3420u32 => "CUTLERY, HANDTOOLS & GENERAL HARDWARE",
+    3430u32 => "HEATING EQUIP, EXCEPT ELEC & WARM AIR; & PLUMBING FIXTURES",
+    3433u32 => "HEATING EQUIPMENT, EXCEPT ELECTRIC & WARM AIR FURNACES",
+    3440u32 => "FABRICATED STRUCTURAL METAL PRODUCTS",
+    3442u32 => "METAL DOORS, SASH, FRAMES, MOLDINGS & TRIM",
+    3443u32 => "FABRICATED PLATE WORK (BOILER SHOPS)",
+    3444u32 => "SHEET METAL WORK",
+    3448u32 => "PREFABRICATED METAL BUILDINGS & COMPONENTS",
+    3451u32 => "SCREW MACHINE PRODUCTS",
+    3452u32 => "BOLTS, NUTS, SCREWS, RIVETS & WASHERS",
+    3460u32 => "METAL FORGINGS & STAMPINGS",
+    3470u32 => "COATING, ENGRAVING & ALLIED SERVICES",
+    3480u32 => "ORDNANCE & ACCESSORIES, (NO VEHICLES/GUIDED MISSILES)",
+    3490u32 => "MISCELLANEOUS FABRICATED METAL PRODUCTS",
+    3510u32 => "ENGINES & TURBINES",
+    3523u32 => "FARM MACHINERY & EQUIPMENT",
+    3524u32 => "LAWN & GARDEN TRACTORS & HOME LAWN & GARDENS EQUIP",
+    3530u32 => "CONSTRUCTION, MINING & MATERIALS HANDLING MACHINERY & EQUIP",
+    3531u32 => "CONSTRUCTION MACHINERY & EQUIP",
+    3532u32 => "MINING MACHINERY & EQUIP (NO OIL & GAS FIELD MACH & EQUIP)",
+    3533u32 => "OIL & GAS FIELD MACHINERY & EQUIPMENT",
+    3537u32 => "INDUSTRIAL TRUCKS, TRACTORS, TRAILERS & STACKERS",
+    3540u32 => "METALWORKG MACHINERY & EQUIPMENT",
+    3541u32 => "MACHINE TOOLS, METAL CUTTING TYPES",
+    3550u32 => "SPECIAL INDUSTRY MACHINERY (NO METALWORKING MACHINERY)",
+    3555u32 => "PRINTING TRADES MACHINERY & EQUIPMENT",
+    3559u32 => "SPECIAL INDUSTRY MACHINERY, NEC",
+    3560u32 => "GENERAL INDUSTRIAL MACHINERY & EQUIPMENT",
+    3561u32 => "PUMPS & PUMPING EQUIPMENT",
+    3562u32 => "BALL & ROLLER BEARINGS",
+    3564u32 => "INDUSTRIAL & COMMERCIAL FANS & BLOWERS & AIR PURIFING EQUIP",
+    3567u32 => "INDUSTRIAL PROCESS FURNACES & OVENS",
+    3569u32 => "GENERAL INDUSTRIAL MACHINERY & EQUIPMENT, NEC",
+    3570u32 => "COMPUTER & OFFICE EQUIPMENT",
+    3571u32 => "ELECTRONIC COMPUTERS",
+    3572u32 => "COMPUTER STORAGE DEVICES",
+    3575u32 => "COMPUTER TERMINALS",
+    3576u32 => "COMPUTER COMMUNICATIONS EQUIPMENT",
+    3577u32 => "COMPUTER PERIPHERAL EQUIPMENT, NEC",
+    3578u32 => "CALCULATING & ACCOUNTING MACHINES (NO ELECTRONIC COMPUTERS)",
+    3579u32 => "OFFICE MACHINES, NEC",
+    3580u32 => "REFRIGERATION & SERVICE INDUSTRY MACHINERY",
+    3585u32 => "AIR-COND & WARM AIR HEATG EQUIP & COMM & INDL REFRIG EQUIP",
+    3590u32 => "MISC INDUSTRIAL & COMMERCIAL MACHINERY & EQUIPMENT",
+    3600u32 => "ELECTRONIC & OTHER ELECTRICAL EQUIPMENT (NO COMPUTER EQUIP)",
+    3612u32 => "POWER, DISTRIBUTION & SPECIALTY TRANSFORMERS",
+    3613u32 => "SWITCHGEAR & SWITCHBOARD APPARATUS",
+    3620u32 => "ELECTRICAL INDUSTRIAL APPARATUS",
+    3621u32 => "MOTORS & GENERATORS",
+    3630u32 => "HOUSEHOLD APPLIANCES",
+    3634u32 => "ELECTRIC HOUSEWARES & FANS",
+    3640u32 => "ELECTRIC LIGHTING & WIRING EQUIPMENT",
+    3651u32 => "HOUSEHOLD AUDIO & VIDEO EQUIPMENT",
+    3652u32 => "PHONOGRAPH RECORDS & PRERECORDED AUDIO TAPES & DISKS",
+    3661u32 => "TELEPHONE & TELEGRAPH APPARATUS",
+    3663u32 => "RADIO & TV BROADCASTING & COMMUNICATIONS EQUIPMENT",
+    3669u32 => "COMMUNICATIONS EQUIPMENT, NEC",
+    3670u32 => "ELECTRONIC COMPONENTS & ACCESSORIES",
+    3672u32 => "PRINTED CIRCUIT BOARDS",
+    3674u32 => "SEMICONDUCTORS & RELATED DEVICES",
+    3677u32 => "ELECTRONIC COILS, TRANSFORMERS & OTHER INDUCTORS",
+    3678u32 => "ELECTRONIC CONNECTORS",
+    3679u32 => "ELECTRONIC COMPONENTS, NEC",
+    3690u32 => "MISCELLANEOUS ELECTRICAL MACHINERY, EQUIPMENT & SUPPLIES",
+    3695u32 => "MAGNETIC & OPTICAL RECORDING MEDIA",
+    3711u32 => "MOTOR VEHICLES & PASSENGER CAR BODIES",
+    3713u32 => "TRUCK & BUS BODIES",
+    3714u32 => "MOTOR VEHICLE PARTS & ACCESSORIES",
+    3715u32 => "TRUCK TRAILERS",
+    3716u32 => "MOTOR HOMES",
+    3720u32 => "AIRCRAFT & PARTS",
+    3721u32 => "AIRCRAFT",
+    3724u32 => "AIRCRAFT ENGINES & ENGINE PARTS",
+    3728u32 => "AIRCRAFT PARTS & AUXILIARY EQUIPMENT, NEC",
+    3730u32 => "SHIP & BOAT BUILDING & REPAIRING",
+    3743u32 => "RAILROAD EQUIPMENT",
+    3751u32 => "MOTORCYCLES, BICYCLES & PARTS",
+    3760u32 => "GUIDED MISSILES & SPACE VEHICLES & PARTS",
+    3790u32 => "MISCELLANEOUS TRANSPORTATION EQUIPMENT",
+    3812u32 => "SEARCH, DETECTION, NAVIGATION, GUIDANCE, AERONAUTICAL SYS",
+    3821u32 => "LABORATORY APPARATUS & FURNITURE",
+    3822u32 => "AUTO CONTROLS FOR REGULATING RESIDENTIAL & COMML ENVIRONMENTS",
+    3823u32 => "INDUSTRIAL INSTRUMENTS FOR MEASUREMENT, DISPLAY, AND CONTROL",
+    3824u32 => "TOTALIZING FLUID METERS & COUNTING DEVICES",
+    3825u32 => "INSTRUMENTS FOR MEAS & TESTING OF ELECTRICITY & ELEC SIGNALS",
+    3826u32 => "LABORATORY ANALYTICAL INSTRUMENTS",
+    3827u32 => "OPTICAL INSTRUMENTS & LENSES",
+    3829u32 => "MEASURING & CONTROLLING DEVICES, NEC",
+    3841u32 => "SURGICAL & MEDICAL INSTRUMENTS & APPARATUS",
+    3842u32 => "ORTHOPEDIC, PROSTHETIC & SURGICAL APPLIANCES & SUPPLIES",
+    3843u32 => "DENTAL EQUIPMENT & SUPPLIES",
+    3844u32 => "X-RAY APPARATUS & TUBES & RELATED IRRADIATION APPARATUS",
+    3845u32 => "ELECTROMEDICAL & ELECTROTHERAPEUTIC APPARATUS",
+    3851u32 => "OPHTHALMIC GOODS",
+    3861u32 => "PHOTOGRAPHIC EQUIPMENT & SUPPLIES",
+    3873u32 => "WATCHES, CLOCKS, CLOCKWORK OPERATED DEVICES/PARTS",
+    3910u32 => "JEWELRY, SILVERWARE & PLATED WARE",
+    3911u32 => "JEWELRY, PRECIOUS METAL",
+    3942u32 => "DOLLS & STUFFED TOYS",
+    3944u32 => "GAMES, TOYS & CHILDREN'S VEHICLES (NO DOLLS & BICYCLES)",
+    3949u32 => "SPORTING & ATHLETIC GOODS, NEC",
+    3950u32 => "PENS, PENCILS & OTHER ARTISTS' MATERIALS",
+    3960u32 => "COSTUME JEWELRY & NOVELTIES",
+    3990u32 => "MISCELLANEOUS MANUFACTURING INDUSTRIES",
+    4011u32 => "RAILROADS, LINE-HAUL OPERATING",
+    4013u32 => "RAILROAD SWITCHING & TERMINAL ESTABLISHMENTS",
+    4100u32 => "LOCAL & SUBURBAN TRANSIT & INTERURBAN HWY PASSENGER TRANS",
+    4210u32 => "TRUCKING & COURIER SERVICES (NO AIR)",
+    4213u32 => "TRUCKING (NO LOCAL)",
+    4220u32 => "PUBLIC WAREHOUSING & STORAGE",
+    4231u32 => "TERMINAL MAINTENANCE FACILITIES FOR MOTOR FREIGHT TRANSPORT",
+    4400u32 => "WATER TRANSPORTATION",
+    4412u32 => "DEEP SEA FOREIGN TRANSPORTATION OF FREIGHT",
+    4512u32 => "AIR TRANSPORTATION, SCHEDULED",
+    4513u32 => "AIR COURIER SERVICES",
+    4522u32 => "AIR TRANSPORTATION, NONSCHEDULED",
+    4581u32 => "AIRPORTS, FLYING FIELDS & AIRPORT TERMINAL SERVICES",
+    4610u32 => "PIPE LINES (NO NATURAL GAS)",
+    4700u32 => "TRANSPORTATION SERVICES",
+    4731u32 => "ARRANGEMENT OF TRANSPORTATION OF FREIGHT & CARGO",
+    4812u32 => "RADIOTELEPHONE COMMUNICATIONS",
+    4813u32 => "TELEPHONE COMMUNICATIONS (NO RADIOTELEPHONE)",
+    4822u32 => "TELEGRAPH & OTHER MESSAGE COMMUNICATIONS",
+    4832u32 => "RADIO BROADCASTING STATIONS",
+    4833u32 => "TELEVISION BROADCASTING STATIONS",
+    4841u32 => "CABLE & OTHER PAY TELEVISION SERVICES",
+    4899u32 => "COMMUNICATIONS SERVICES, NEC",
+    4900u32 => "ELECTRIC, GAS & SANITARY SERVICES",
+    4911u32 => "ELECTRIC SERVICES",
+    4922u32 => "NATURAL GAS TRANSMISSION",
+    4923u32 => "NATURAL GAS TRANSMISSION & DISTRIBUTION",
+    4924u32 => "NATURAL GAS DISTRIBUTION",
+    4931u32 => "ELECTRIC & OTHER SERVICES COMBINED",
+    4932u32 => "GAS & OTHER SERVICES COMBINED",
+    4940u32 => "WATER SUPPLY",
+    4941u32 => "WATER SUPPLY",
+    4950u32 => "SANITARY SERVICES",
+    4953u32 => "REFUSE SYSTEMS",
+    4955u32 => "HAZARDOUS WASTE MANAGEMENT",
+    4961u32 => "STEAM & AIR-CONDITIONING SUPPLY",
+    4991u32 => "COGENERATION SERVICES & SMALL POWER PRODUCERS",
+    5000u32 => "WHOLESALE-DURABLE GOODS",
+    5010u32 => "WHOLESALE-MOTOR VEHICLES & MOTOR VEHICLE PARTS & SUPPLIES",
+    5013u32 => "WHOLESALE-MOTOR VEHICLE SUPPLIES & NEW PARTS",
+    5020u32 => "WHOLESALE-FURNITURE & HOME FURNISHINGS",
+    5030u32 => "WHOLESALE-LUMBER & OTHER CONSTRUCTION MATERIALS",
+    5031u32 => "WHOLESALE-LUMBER, PLYWOOD, MILLWORK & WOOD PANELS",
+    5040u32 => "WHOLESALE-PROFESSIONAL & COMMERCIAL EQUIPMENT & SUPPLIES",
+    5045u32 => "WHOLESALE-COMPUTERS & PERIPHERAL EQUIPMENT & SOFTWARE",
+    5047u32 => "WHOLESALE-MEDICAL, DENTAL & HOSPITAL EQUIPMENT & SUPPLIES",
+    5050u32 => "WHOLESALE-METALS SERVICE CENTERS & OFFICES",
+    5051u32 => "WHOLESALE-METALS SERVICE CENTERS & OFFICES",
+    5063u32 => "WHOLESALE-ELECTRICAL APPARATUS & EQUIPMENT, WIRING SUPPLIES",
+    5064u32 => "WHOLESALE-ELECTRICAL APPLIANCES, TV & RADIO SETS",
+    5065u32 => "WHOLESALE-ELECTRONIC PARTS & EQUIPMENT, NEC",
+    5070u32 => "WHOLESALE-HARDWARE & PLUMBING & HEATING EQUIPMENT & SUPPLIES",
+    5072u32 => "WHOLESALE-HARDWARE",
+    5080u32 => "WHOLESALE-MACHINERY, EQUIPMENT & SUPPLIES",
+    5082u32 => "WHOLESALE-CONSTRUCTION & MINING (NO PETRO) MACHINERY & EQUIP",
+    5084u32 => "WHOLESALE-INDUSTRIAL MACHINERY & EQUIPMENT",
+    5090u32 => "WHOLESALE-MISC DURABLE GOODS",
+    5094u32 => "WHOLESALE-JEWELRY, WATCHES, PRECIOUS STONES & METALS",
+    5099u32 => "WHOLESALE-DURABLE GOODS, NEC",
+    5110u32 => "WHOLESALE-PAPER AND PAPER PRODUCTS",
+    5122u32 => "WHOLESALE-DRUGS PROPRIETARIES & DRUGGISTS' SUNDRIES",
+    5130u32 => "WHOLESALE-APPAREL, PIECE GOODS & NOTIONS",
+    5140u32 => "WHOLESALE-GROCERIES & RELATED PRODUCTS",
+    5141u32 => "WHOLESALE-GROCERIES, GENERAL LINE",
+    5150u32 => "WHOLESALE-FARM PRODUCT RAW MATERIALS",
+    5160u32 => "WHOLESALE-CHEMICALS & ALLIED PRODUCTS",
+    5171u32 => "WHOLESALE-PETROLEUM BULK STATIONS & TERMINALS",
+    5172u32 => "WHOLESALE-PETROLEUM & PETROLEUM PRODUCTS (NO BULK STATIONS)",
+    5180u32 => "WHOLESALE-BEER, WINE & DISTILLED ALCOHOLIC BEVERAGES",
+    5190u32 => "WHOLESALE-MISCELLANEOUS NONDURABLE GOODS",
+    5200u32 => "RETAIL-BUILDING MATERIALS, HARDWARE, GARDEN SUPPLY",
+    5211u32 => "RETAIL-LUMBER & OTHER BUILDING MATERIALS DEALERS",
+    5271u32 => "RETAIL-MOBILE HOME DEALERS",
+    5311u32 => "RETAIL-DEPARTMENT STORES",
+    5331u32 => "RETAIL-VARIETY STORES",
+    5399u32 => "RETAIL-MISC GENERAL MERCHANDISE STORES",
+    5400u32 => "RETAIL-FOOD STORES",
+    5411u32 => "RETAIL-GROCERY STORES",
+    5412u32 => "RETAIL-CONVENIENCE STORES",
+    5500u32 => "RETAIL-AUTO DEALERS & GASOLINE STATIONS",
+    5531u32 => "RETAIL-AUTO & HOME SUPPLY STORES",
+    5600u32 => "RETAIL-APPAREL & ACCESSORY STORES",
+    5621u32 => "RETAIL-WOMEN'S CLOTHING STORES",
+    5651u32 => "RETAIL-FAMILY CLOTHING STORES",
+    5661u32 => "RETAIL-SHOE STORES",
+    5700u32 => "RETAIL-HOME FURNITURE, FURNISHINGS & EQUIPMENT STORES",
+    5712u32 => "RETAIL-FURNITURE STORES",
+    5731u32 => "RETAIL-RADIO, TV & CONSUMER ELECTRONICS STORES",
+    5734u32 => "RETAIL-COMPUTER & COMPUTER SOFTWARE STORES",
+    5735u32 => "RETAIL-RECORD & PRERECORDED TAPE STORES",
+    5810u32 => "RETAIL-EATING & DRINKING PLACES",
+    5812u32 => "RETAIL-EATING PLACES",
+    5900u32 => "RETAIL-MISCELLANEOUS RETAIL",
+    5912u32 => "RETAIL-DRUG STORES AND PROPRIETARY STORES",
+    5940u32 => "RETAIL-MISCELLANEOUS SHOPPING GOODS STORES",
+    5944u32 => "RETAIL-JEWELRY STORES",
+    5945u32 => "RETAIL-HOBBY, TOY & GAME SHOPS",
+    5960u32 => "RETAIL-NONSTORE RETAILERS",
+    5961u32 => "RETAIL-CATALOG & MAIL-ORDER HOUSES",
+    5990u32 => "RETAIL-RETAIL STORES, NEC",
+    6021u32 => "NATIONAL COMMERCIAL BANKS",
+    6022u32 => "STATE COMMERCIAL BANKS",
+    6029u32 => "COMMERCIAL BANKS, NEC",
+    6035u32 => "SAVINGS INSTITUTION, FEDERALLY CHARTERED",
+    6036u32 => "SAVINGS INSTITUTIONS, NOT FEDERALLY CHARTERED",
+    6099u32 => "FUNCTIONS RELATED TO DEPOSITORY BANKING, NEC",
+    6111u32 => "FEDERAL & FEDERALLY-SPONSORED CREDIT AGENCIES",
+    6141u32 => "PERSONAL CREDIT INSTITUTIONS",
+    6153u32 => "SHORT-TERM BUSINESS CREDIT INSTITUTIONS",
+    6159u32 => "MISCELLANEOUS BUSINESS CREDIT INSTITUTION",
+    6162u32 => "MORTGAGE BANKERS & LOAN CORRESPONDENTS",
+    6163u32 => "LOAN BROKERS",
+    6172u32 => "FINANCE LESSORS",
+    6189u32 => "ASSET-BACKED SECURITIES",
+    6199u32 => "FINANCE SERVICES",
+    6200u32 => "SECURITY & COMMODITY BROKERS, DEALERS, EXCHANGES & SERVICES",
+    6211u32 => "SECURITY BROKERS, DEALERS & FLOTATION COMPANIES",
+    6221u32 => "COMMODITY CONTRACTS BROKERS & DEALERS",
+    6282u32 => "INVESTMENT ADVICE",
+    6311u32 => "LIFE INSURANCE",
+    6321u32 => "ACCIDENT & HEALTH INSURANCE",
+    6324u32 => "HOSPITAL & MEDICAL SERVICE PLANS",
+    6331u32 => "FIRE, MARINE & CASUALTY INSURANCE",
+    6351u32 => "SURETY INSURANCE",
+    6361u32 => "TITLE INSURANCE",
+    6399u32 => "INSURANCE CARRIERS, NEC",
+    6411u32 => "INSURANCE AGENTS BROKERS & SERVICE",
+    6500u32 => "REAL ESTATE",
+    6510u32 => "REAL ESTATE OPERATORS (NO DEVELOPERS) & LESSORS",
+    6512u32 => "OPERATORS OF NONRESIDENTIAL BUILDINGS",
+    6513u32 => "OPERATORS OF APARTMENT BUILDINGS",
+    6519u32 => "LESSORS OF REAL PROPERTY, NEC",
+    6531u32 => "REAL ESTATE AGENTS & MANAGERS (FOR OTHERS)",
+    6532u32 => "REAL ESTATE DEALERS (FOR THEIR OWN ACCOUNT)",
+    6552u32 => "LAND SUBDIVIDERS & DEVELOPERS (NO CEMETERIES)",
+    6770u32 => "BLANK CHECKS",
+    6792u32 => "OIL ROYALTY TRADERS",
+    6794u32 => "PATENT OWNERS & LESSORS",
+    6795u32 => "MINERAL ROYALTY TRADERS",
+    6798u32 => "REAL ESTATE INVESTMENT TRUSTS",
+    6799u32 => "INVESTORS, NEC",
+    7000u32 => "HOTELS, ROOMING HOUSES, CAMPS & OTHER LODGING PLACES",
+    7011u32 => "HOTELS & MOTELS",
+    7200u32 => "SERVICES-PERSONAL SERVICES",
+    7310u32 => "SERVICES-ADVERTISING",
+    7311u32 => "SERVICES-ADVERTISING AGENCIES",
+    7320u32 => "SERVICES-CONSUMER CREDIT REPORTING, COLLECTION AGENCIES",
+    7330u32 => "SERVICES-MAILING, REPRODUCTION, COMMERCIAL ART & PHOTOGRAPHY",
+    7331u32 => "SERVICES-DIRECT MAIL ADVERTISING SERVICES",
+    7340u32 => "SERVICES-TO DWELLINGS & OTHER BUILDINGS",
+    7350u32 => "SERVICES-MISCELLANEOUS EQUIPMENT RENTAL & LEASING",
+    7359u32 => "SERVICES-EQUIPMENT RENTAL & LEASING, NEC",
+    7361u32 => "SERVICES-EMPLOYMENT AGENCIES",
+    7363u32 => "SERVICES-HELP SUPPLY SERVICES",
+    7370u32 => "SERVICES-COMPUTER PROGRAMMING, DATA PROCESSING, ETC.",
+    7371u32 => "SERVICES-COMPUTER PROGRAMMING SERVICES",
+    7372u32 => "SERVICES-PREPACKAGED SOFTWARE",
+    7373u32 => "SERVICES-COMPUTER INTEGRATED SYSTEMS DESIGN",
+    7374u32 => "SERVICES-COMPUTER PROCESSING & DATA PREPARATION",
+    7377u32 => "SERVICES-COMPUTER RENTAL & LEASING",
+    7380u32 => "SERVICES-MISCELLANEOUS BUSINESS SERVICES",
+    7381u32 => "SERVICES-DETECTIVE, GUARD & ARMORED CAR SERVICES",
+    7384u32 => "SERVICES-PHOTOFINISHING LABORATORIES",
+    7385u32 => "SERVICES-TELEPHONE INTERCONNECT SYSTEMS",
+    7389u32 => "SERVICES-BUSINESS SERVICES, NEC",
+    7500u32 => "SERVICES-AUTOMOTIVE REPAIR, SERVICES & PARKING",
+    7510u32 => "SERVICES-AUTO RENTAL & LEASING (NO DRIVERS)",
+    7600u32 => "SERVICES-MISCELLANEOUS REPAIR SERVICES",
+    7812u32 => "SERVICES-MOTION PICTURE & VIDEO TAPE PRODUCTION",
+    7819u32 => "SERVICES-ALLIED TO MOTION PICTURE PRODUCTION",
+    7822u32 => "SERVICES-MOTION PICTURE & VIDEO TAPE DISTRIBUTION",
+    7829u32 => "SERVICES-ALLIED TO MOTION PICTURE DISTRIBUTION",
+    7830u32 => "SERVICES-MOTION PICTURE THEATERS",
+    7841u32 => "SERVICES-VIDEO TAPE RENTAL",
+    7900u32 => "SERVICES-AMUSEMENT & RECREATION SERVICES",
+    7948u32 => "SERVICES-RACING, INCLUDING TRACK OPERATION",
+    7990u32 => "SERVICES-MISCELLANEOUS AMUSEMENT & RECREATION",
+    7997u32 => "SERVICES-MEMBERSHIP SPORTS & RECREATION CLUBS",
+    8000u32 => "SERVICES-HEALTH SERVICES",
+    8011u32 => "SERVICES-OFFICES & CLINICS OF DOCTORS OF MEDICINE",
+    8050u32 => "SERVICES-NURSING & PERSONAL CARE FACILITIES",
+    8051u32 => "SERVICES-SKILLED NURSING CARE FACILITIES",
+    8060u32 => "SERVICES-HOSPITALS",
+    8062u32 => "SERVICES-GENERAL MEDICAL & SURGICAL HOSPITALS, NEC",
+    8071u32 => "SERVICES-MEDICAL LABORATORIES",
+    8082u32 => "SERVICES-HOME HEALTH CARE SERVICES",
+    8090u32 => "SERVICES-MISC HEALTH & ALLIED SERVICES, NEC",
+    8093u32 => "SERVICES-SPECIALTY OUTPATIENT FACILITIES, NEC",
+    8111u32 => "SERVICES-LEGAL SERVICES",
+    8200u32 => "SERVICES-EDUCATIONAL SERVICES",
+    8300u32 => "SERVICES-SOCIAL SERVICES",
+    8351u32 => "SERVICES-CHILD DAY CARE SERVICES",
+    8600u32 => "SERVICES-MEMBERSHIP ORGANIZATIONS",
+    8700u32 => "SERVICES-ENGINEERING, ACCOUNTING, RESEARCH, MANAGEMENT",
+    8711u32 => "SERVICES-ENGINEERING SERVICES",
+    8731u32 => "SERVICES-COMMERCIAL PHYSICAL & BIOLOGICAL RESEARCH",
+    8734u32 => "SERVICES-TESTING LABORATORIES",
+    8741u32 => "SERVICES-MANAGEMENT SERVICES",
+    8742u32 => "SERVICES-MANAGEMENT CONSULTING SERVICES",
+    8744u32 => "SERVICES-FACILITIES SUPPORT MANAGEMENT SERVICES",
+    8900u32 => "SERVICES-SERVICES, NEC",
+    9721u32 => "INTERNATIONAL AFFAIRS",
+    9995u32 => "NON-OPERATING ESTABLISHMENTS",
+};
+
+/// Returns the SEC's industry title for a Standard Industrial Classification (SIC) code, the
+/// same text the submissions JSON reports as `sicDescription`, or `None` for an unknown code.
+pub fn sic_description(sic: u32) -> Option<&'static str> {
+    SIC_DESCRIPTION_MAP.get(&sic).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sic_description_known_codes() {
+        assert_eq!(sic_description(3571), Some("ELECTRONIC COMPUTERS"));
+        assert_eq!(sic_description(7372), Some("SERVICES-PREPACKAGED SOFTWARE"));
+        assert_eq!(sic_description(6798), Some("REAL ESTATE INVESTMENT TRUSTS"));
+    }
+
+    #[test]
+    fn test_sic_description_unknown_code() {
+        assert_eq!(sic_description(1234), None);
+    }
+}
@@ -0,0 +1,970 @@
+use crate::config::ARCHIVES_BASE_URL;
+use crate::error::EDGARParserError;
+use crate::filing_type_builder::FilingTypeOption;
+use chrono::{DateTime, Datelike, Months, NaiveDate, TimeDelta, Utc};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Forms whose XBRL tags only the cover page (registrant name, CIK, securities), so they
+// carry no financial facts even when flagged as (inline) XBRL.
+const COVER_PAGE_ONLY_FORMS: [&str; 2] = ["8-K", "8-K/A"];
+
+// Drift allowed between a filing and its counterpart a year earlier in `next_expected`.
+const CADENCE_SLACK: TimeDelta = TimeDelta::days(30);
+
+/// Describes how the financial data in a filing was tagged, so callers can route
+/// it to the correct parser.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum XbrlKind {
+    /// XBRL embedded directly in the HTML primary document (iXBRL).
+    InlineXbrl,
+    /// XBRL delivered as separate instance documents alongside the filing.
+    TraditionalXbrl,
+    /// The filing carries no XBRL data.
+    None,
+}
+
+/// The form type of a filing as listed in its submissions. Submissions include forms that
+/// cannot be queried through `FilingTypeOption`, so those keep their raw name.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub enum FormType {
+    /// A form the EDGAR browse interface can be queried for.
+    Known(FilingTypeOption),
+    /// Any other form, e.g. `NOT-A-FORM`, as the SEC lists it.
+    Unknown(String),
+}
+
+impl FormType {
+    /// Parses a form name as listed in submissions, e.g. `10-K`.
+    pub fn parse(form: &str) -> Self {
+        form.parse()
+            .map_or_else(|_| Self::Unknown(form.to_string()), Self::Known)
+    }
+}
+
+impl fmt::Display for FormType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Known(form) => write!(f, "{}", form),
+            Self::Unknown(form) => write!(f, "{}", form),
+        }
+    }
+}
+
+/// Represents a single filing entry from the SEC Company Submissions JSON.
+///
+/// # Fields
+/// - `accession_number`: The accession number, e.g. `0000320193-23-000106`.
+/// - `filing_date`: The date the filing was made, formatted `YYYY-MM-DD`.
+/// - `acceptance_datetime`: When EDGAR accepted the filing, if the SEC reports it.
+/// - `report_date`: The period of report, e.g. the fiscal year end a 10-K covers. `None` for
+///   forms without one, which the SEC reports as an empty string.
+/// - `form`: The form type as reported by the SEC (e.g. `10-K`).
+/// - `file_number`: The SEC file number of the registration the filing belongs to, e.g.
+///   `001-36743`. `None` when the SEC lists none.
+/// - `film_number`: The film number the SEC assigned when the filing was accepted.
+///   `None` when the SEC lists none.
+/// - `primary_document`: File name of the primary document within the filing.
+/// - `primary_doc_description`: Description of the primary document.
+/// - `is_xbrl`: Whether the filing includes XBRL data.
+/// - `is_inline_xbrl`: Whether the XBRL data is embedded inline in the primary document.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Filing {
+    pub accession_number: String,
+    pub filing_date: String,
+    #[serde(
+        rename = "acceptanceDateTime",
+        default,
+        deserialize_with = "optional_datetime"
+    )]
+    pub acceptance_datetime: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "optional_date")]
+    pub report_date: Option<NaiveDate>,
+    pub form: String,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub file_number: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub film_number: Option<String>,
+    #[serde(default)]
+    pub primary_document: String,
+    #[serde(default)]
+    pub primary_doc_description: String,
+
+    #[serde(rename = "isXBRL", default, deserialize_with = "int_to_bool")]
+    pub is_xbrl: bool,
+
+    #[serde(rename = "isInlineXBRL", default, deserialize_with = "int_to_bool")]
+    pub is_inline_xbrl: bool,
+}
+
+/// Represents the SEC Company Submissions JSON for a single company.
+///
+/// # Fields
+/// - `cik`: The company's CIK as reported by the SEC (unpadded string).
+/// - `name`: The company's current name.
+/// - `sic`: The company's Standard Industrial Classification code, e.g. `3571`. `None` when
+///   the SEC lists none.
+/// - `sic_description`: Readable form of `sic`, e.g. `Electronic Computers`.
+/// - `tickers`: Ticker symbols the SEC associates with the company.
+/// - `exchanges`: Exchanges the company's securities trade on, e.g. `Nasdaq`.
+/// - `former_names`: Names the company previously filed under.
+/// - `insider_transaction_for_owner_exists`: Whether the company has filed insider
+///   transaction reports (Forms 3, 4 and 5) as a reporting owner.
+/// - `insider_transaction_for_issuer_exists`: Whether insider transaction reports have been
+///   filed about the company's securities, i.e. with the company as the issuer.
+/// - `addresses`: The company's mailing and business addresses.
+/// - `filings`: The company's filing history.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Submissions {
+    pub cik: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub sic: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub sic_description: Option<String>,
+    #[serde(default)]
+    pub tickers: Vec<String>,
+    #[serde(default, deserialize_with = "present_strings")]
+    pub exchanges: Vec<String>,
+    #[serde(default)]
+    pub former_names: Vec<FormerName>,
+    #[serde(default, deserialize_with = "int_to_bool")]
+    pub insider_transaction_for_owner_exists: bool,
+    #[serde(default, deserialize_with = "int_to_bool")]
+    pub insider_transaction_for_issuer_exists: bool,
+    #[serde(default)]
+    pub addresses: Addresses,
+    #[serde(default)]
+    pub filings: SubmissionFilings,
+}
+
+/// Represents the `addresses` block of the submissions JSON.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(default)]
+pub struct Addresses {
+    pub mailing: Option<Address>,
+    pub business: Option<Address>,
+}
+
+/// Represents a postal address registered with the SEC. The SEC leaves unused lines `null`.
+///
+/// # Fields
+/// - `street1`: First street line.
+/// - `street2`: Second street line, if any.
+/// - `city`: City name.
+/// - `state_or_country`: State code for US addresses (e.g. `CA`), otherwise an EDGAR country code.
+/// - `state_or_country_description`: Readable form of `state_or_country`.
+/// - `zip_code`: Postal code.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Address {
+    pub street1: Option<String>,
+    pub street2: Option<String>,
+    pub city: Option<String>,
+    pub state_or_country: Option<String>,
+    pub state_or_country_description: Option<String>,
+    pub zip_code: Option<String>,
+}
+
+/// Represents a name a company previously filed under.
+///
+/// # Fields
+/// - `name`: The former name.
+/// - `from`: When the name came into use, as an ISO 8601 timestamp.
+/// - `to`: When the name stopped being used, as an ISO 8601 timestamp.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(default)]
+pub struct FormerName {
+    pub name: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Represents the `filings` block of the submissions JSON.
+///
+/// # Fields
+/// - `recent`: At least a year (or 1,000) of the most recent filings.
+/// - `files`: Supplemental files holding the company's older filings, if any.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+pub struct SubmissionFilings {
+    #[serde(default)]
+    pub recent: RecentFilings,
+    #[serde(default)]
+    pub files: Vec<SupplementalFile>,
+}
+
+/// Describes one supplemental submissions file, e.g. `CIK0000320193-submissions-001.json`.
+/// Each file holds older filings in the same columnar format as `RecentFilings`.
+///
+/// # Fields
+/// - `name`: File name, relative to the `submissions` endpoint.
+/// - `filing_count`: Number of filings in the file.
+/// - `filing_from`: Earliest filing date in the file, formatted `YYYY-MM-DD`.
+/// - `filing_to`: Latest filing date in the file, formatted `YYYY-MM-DD`.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SupplementalFile {
+    pub name: String,
+    pub filing_count: usize,
+    pub filing_from: String,
+    pub filing_to: String,
+}
+
+/// Represents the most recent filings in the SEC's compact columnar format, where
+/// the n-th element of every column describes the n-th filing.
+#[derive(Debug, Default, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecentFilings {
+    pub accession_number: Vec<String>,
+    pub filing_date: Vec<String>,
+    #[serde(rename = "acceptanceDateTime")]
+    pub acceptance_datetime: Vec<String>,
+    pub report_date: Vec<String>,
+    pub form: Vec<String>,
+    pub file_number: Vec<String>,
+    pub film_number: Vec<String>,
+    pub primary_document: Vec<String>,
+    pub primary_doc_description: Vec<String>,
+    #[serde(rename = "isXBRL")]
+    pub is_xbrl: Vec<u8>,
+    #[serde(rename = "isInlineXBRL")]
+    pub is_inline_xbrl: Vec<u8>,
+}
+
+impl RecentFilings {
+    /// Returns the number of filings, taken from the accession number column.
+    pub fn len(&self) -> usize {
+        self.accession_number.len()
+    }
+
+    /// Returns `true` when there are no recent filings.
+    pub fn is_empty(&self) -> bool {
+        self.accession_number.is_empty()
+    }
+
+    /// Assembles the filing at `index` from the columns. Columns the SEC omitted entirely fall
+    /// back to their default values. Returns `None` if `index` is past the end of the
+    /// accession number column or of any column present, so mismatched columns never
+    /// produce a misaligned filing. A column that is present but shorter than the others is
+    /// not padded with defaults: the rows it does not reach return `None`.
+    pub fn filing(&self, index: usize) -> Option<Filing> {
+        let column = |values: &[String]| match values.get(index) {
+            Some(value) => Some(value.clone()),
+            None if values.is_empty() => Some(String::new()),
+            None => None,
+        };
+        let flag = |values: &[u8]| match values.get(index) {
+            Some(flag) => Some(*flag != 0),
+            None if values.is_empty() => Some(false),
+            None => None,
+        };
+
+        Some(Filing {
+            accession_number: self.accession_number.get(index)?.clone(),
+            filing_date: column(&self.filing_date)?,
+            acceptance_datetime: parse_datetime(&column(&self.acceptance_datetime)?),
+            report_date: parse_report_date(&column(&self.report_date)?),
+            form: column(&self.form)?,
+            file_number: Some(column(&self.file_number)?).filter(|n| !n.is_empty()),
+            film_number: Some(column(&self.film_number)?).filter(|n| !n.is_empty()),
+            primary_document: column(&self.primary_document)?,
+            primary_doc_description: column(&self.primary_doc_description)?,
+            is_xbrl: flag(&self.is_xbrl)?,
+            is_inline_xbrl: flag(&self.is_inline_xbrl)?,
+        })
+    }
+
+    /// Checks that every column present has one entry per accession number. Columns the SEC
+    /// omitted entirely are allowed; a column of any other length means the rows would be
+    /// misaligned.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::UnexpectedSchema` naming the first mismatched column.
+    pub fn validate(&self) -> Result<(), EDGARParserError> {
+        let expected = self.len();
+        let columns = [
+            ("filingDate", self.filing_date.len()),
+            ("acceptanceDateTime", self.acceptance_datetime.len()),
+            ("reportDate", self.report_date.len()),
+            ("form", self.form.len()),
+            ("fileNumber", self.file_number.len()),
+            ("filmNumber", self.film_number.len()),
+            ("primaryDocument", self.primary_document.len()),
+            ("primaryDocDescription", self.primary_doc_description.len()),
+            ("isXBRL", self.is_xbrl.len()),
+            ("isInlineXBRL", self.is_inline_xbrl.len()),
+        ];
+
+        match columns
+            .iter()
+            .find(|(_, len)| *len != 0 && *len != expected)
+        {
+            Some((name, len)) => Err(EDGARParserError::UnexpectedSchema(format!(
+                "filings column `{}` has {} entries, expected {}",
+                name, len, expected
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates the columns and assembles every filing, in column order.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::UnexpectedSchema` if the columns differ in length.
+    pub fn to_filings(&self) -> Result<Vec<Filing>, EDGARParserError> {
+        self.validate()?;
+        Ok(self.iter_filings().collect())
+    }
+
+    /// Iterates over every filing, in the order of the columns (newest first as published).
+    /// Rows that a shorter column does not reach are skipped; use `to_filings` to reject
+    /// mismatched columns instead.
+    pub fn iter_filings(&self) -> impl Iterator<Item = Filing> + '_ {
+        (0..self.len()).filter_map(|i| self.filing(i))
+    }
+}
+
+impl Submissions {
+    /// Iterates over the company's recent filings, zipping the columns of `filings.recent`
+    /// into one `Filing` each, newest first. See `RecentFilings::iter_filings`.
+    pub fn recent_filings(&self) -> impl Iterator<Item = Filing> + '_ {
+        self.filings.recent.iter_filings()
+    }
+
+    /// Returns the name the company filed under while it used `ticker`, or `None` if its
+    /// submissions show no sign of the ticker. A ticker the company still lists maps to its
+    /// current name. A ticker it gave up is recognized from primary documents named after it,
+    /// e.g. `meta-20211231.htm` as the EDGAR Filer Manual recommends for inline XBRL; the name
+    /// is then the one in `former_names` in use at the latest such filing.
+    pub fn name_while_holding(&self, ticker: &str) -> Option<String> {
+        if self.tickers.iter().any(|t| t.eq_ignore_ascii_case(ticker)) {
+            return Some(self.name.clone());
+        }
+
+        let prefix = format!("{}-", ticker.to_ascii_lowercase());
+        let filing = self.recent_filings().find(|f| {
+            f.primary_document
+                .to_ascii_lowercase()
+                .strip_prefix(&prefix)
+                .and_then(|rest| rest.get(..8))
+                .is_some_and(|date| date.chars().all(|c| c.is_ascii_digit()))
+        })?;
+
+        let filed = filing.filing_date.as_str();
+        let former = self.former_names.iter().find(|former| {
+            let day = |timestamp: &str| timestamp.get(..10).unwrap_or_default().to_string();
+            day(&former.from).as_str() <= filed && filed <= day(&former.to).as_str()
+        });
+
+        Some(former.map_or_else(|| self.name.clone(), |former| former.name.clone()))
+    }
+
+    /// Returns the company's mailing address, if the SEC lists one.
+    pub fn mailing_address(&self) -> Option<&Address> {
+        self.addresses.mailing.as_ref()
+    }
+
+    /// Returns the company's business address, if the SEC lists one.
+    pub fn business_address(&self) -> Option<&Address> {
+        self.addresses.business.as_ref()
+    }
+
+    /// Returns the most recently filed entry whose form matches `form` exactly (e.g. `10-K`).
+    pub fn latest_filing(&self, form: &str) -> Option<Filing> {
+        self.filings
+            .recent
+            .iter_filings()
+            .filter(|f| f.form == form)
+            .max_by(|a, b| a.filing_date.cmp(&b.filing_date))
+    }
+
+    /// Groups the recent filings by form type, each group newest first as published. Forms
+    /// that do not parse as a `FilingTypeOption` are grouped by their raw name under
+    /// `FormType::Unknown`, after every known form. Filings are assembled from the columnar
+    /// data, so the groups hold owned `Filing`s.
+    pub fn by_form(&self) -> BTreeMap<FormType, Vec<Filing>> {
+        let mut groups: BTreeMap<FormType, Vec<Filing>> = BTreeMap::new();
+
+        for filing in self.filings.recent.iter_filings() {
+            groups
+                .entry(FormType::parse(&filing.form))
+                .or_default()
+                .push(filing);
+        }
+
+        groups
+    }
+
+    /// Returns the recent filings made after `since`, newest first as published. Filings
+    /// are compared by `acceptance_datetime`; a filing without one is included when its
+    /// `filing_date` is on or after the day of `since`, so a poller may see it twice but
+    /// never misses it.
+    pub fn filings_since(&self, since: DateTime<Utc>) -> Vec<Filing> {
+        let since_date = since.date_naive();
+
+        self.filings
+            .recent
+            .iter_filings()
+            .filter(|f| match f.acceptance_datetime {
+                Some(accepted) => accepted > since,
+                None => NaiveDate::parse_from_str(&f.filing_date, "%Y-%m-%d")
+                    .is_ok_and(|date| date >= since_date),
+            })
+            .collect()
+    }
+
+    /// Estimates when the company will next file `form`, from the cadence of its recent
+    /// filings of that form. This is a heuristic, not a deadline calculation: it ignores
+    /// filer status, fiscal year changes and late filings.
+    ///
+    /// With at least a year of history, the next filing is expected a year after the first
+    /// one that followed this time last year (allowing a month of drift), which keeps
+    /// seasonal gaps (no 10-Q in the quarter a 10-K is filed). With a shorter history, the
+    /// median gap between filings is added to the latest one. Returns `None` with fewer than
+    /// two dated filings of `form`.
+    pub fn next_expected(&self, form: FilingTypeOption) -> Option<NaiveDate> {
+        let form = form.to_string();
+        let mut dates: Vec<NaiveDate> = self
+            .filings
+            .recent
+            .iter_filings()
+            .filter(|f| f.form == form)
+            .filter_map(|f| NaiveDate::parse_from_str(&f.filing_date, "%Y-%m-%d").ok())
+            .collect();
+        dates.sort();
+        dates.dedup();
+
+        if dates.len() < 2 {
+            return None;
+        }
+        let (first, last) = (dates[0], dates[dates.len() - 1]);
+
+        let year_ago = last.checked_sub_months(Months::new(12))?;
+        if first <= year_ago {
+            // Filings within the slack of this time last year are the counterpart of `last`.
+            let cutoff = year_ago + CADENCE_SLACK;
+            let anniversary = dates.iter().find(|&&d| d > cutoff).unwrap_or(&last);
+            return anniversary.checked_add_months(Months::new(12));
+        }
+
+        let mut gaps: Vec<TimeDelta> = dates.windows(2).map(|w| w[1] - w[0]).collect();
+        gaps.sort();
+        last.checked_add_signed(gaps[gaps.len() / 2])
+    }
+
+    /// Counts the company's recent filings per calendar year of their `filing_date`.
+    /// Dates that are not formatted `YYYY-MM-DD` are skipped.
+    pub fn counts_by_year(&self) -> BTreeMap<i32, usize> {
+        let mut counts = BTreeMap::new();
+
+        for date in &self.filings.recent.filing_date {
+            if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                *counts.entry(date.year()).or_insert(0) += 1;
+            }
+        }
+
+        counts
+    }
+}
+
+/// Custom deserializer for the `0`/`1` flags the SEC uses in place of booleans.
+fn int_to_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let flag: u8 = Deserialize::deserialize(deserializer)?;
+    Ok(flag != 0)
+}
+
+/// Deserializes a `YYYY-MM-DD` date that the SEC leaves empty when it does not apply.
+fn optional_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let date: String = Deserialize::deserialize(deserializer)?;
+    Ok(parse_report_date(&date))
+}
+
+/// Deserializes an RFC 3339 timestamp such as `2023-11-02T18:01:14.000Z`, which the SEC
+/// leaves empty when it is unknown.
+fn optional_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let datetime: String = Deserialize::deserialize(deserializer)?;
+    Ok(parse_datetime(&datetime))
+}
+
+/// Parses a submissions timestamp entry, treating empty or malformed values as absent.
+fn parse_datetime(datetime: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(datetime)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Deserializes a string that the SEC leaves empty or `null` when it does not apply.
+fn non_empty<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<String> = Deserialize::deserialize(deserializer)?;
+    Ok(value.filter(|v| !v.is_empty()))
+}
+
+/// Deserializes a list of strings, dropping the `null` entries the SEC uses for securities
+/// that are not listed on an exchange.
+fn present_strings<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values: Vec<Option<String>> = Deserialize::deserialize(deserializer)?;
+    Ok(values.into_iter().flatten().collect())
+}
+
+/// Parses a submissions date column entry, treating empty or malformed values as absent.
+fn parse_report_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+impl Filing {
+    /// Classifies the filing as inline XBRL, traditional XBRL, or untagged.
+    /// Inline XBRL takes precedence, since an iXBRL filing is also flagged as XBRL.
+    pub fn xbrl_kind(&self) -> XbrlKind {
+        if self.is_inline_xbrl {
+            XbrlKind::InlineXbrl
+        } else if self.is_xbrl {
+            XbrlKind::TraditionalXbrl
+        } else {
+            XbrlKind::None
+        }
+    }
+
+    /// Returns `true` if the filing's financial data is XBRL-tagged, so its facts can be read
+    /// from the XBRL instance (or the company facts and frames APIs) rather than parsed out of
+    /// the document text. Forms that only tag their cover page, such as 8-Ks, are not
+    /// considered structured even though the SEC flags them as inline XBRL.
+    pub fn has_structured_data(&self) -> bool {
+        self.xbrl_kind() != XbrlKind::None && !COVER_PAGE_ONLY_FORMS.contains(&self.form.as_str())
+    }
+
+    /// Builds the EDGAR Archives URL of this filing's primary document for the company `cik`.
+    /// The archive folder uses the accession number without dashes and the unpadded CIK, e.g.
+    /// `https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm`.
+    pub fn primary_document_url(&self, cik: u32) -> String {
+        self.primary_document_url_at(ARCHIVES_BASE_URL, cik)
+    }
+
+    /// Builds the primary document URL under an alternate archives base URL.
+    pub(crate) fn primary_document_url_at(&self, archives_base_url: &str, cik: u32) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            archives_base_url,
+            cik,
+            self.accession_number.replace('-', ""),
+            self.primary_document
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filing_fixture(is_xbrl: u8, is_inline_xbrl: u8) -> Filing {
+        let json = format!(
+            r#"
+            {{
+                "accessionNumber": "0000320193-23-000106",
+                "filingDate": "2023-11-03",
+                "reportDate": "2023-09-30",
+                "form": "10-K",
+                "primaryDocument": "aapl-20230930.htm",
+                "primaryDocDescription": "10-K",
+                "isXBRL": {is_xbrl},
+                "isInlineXBRL": {is_inline_xbrl}
+            }}
+        "#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_filing_flags() {
+        let filing = filing_fixture(1, 0);
+        assert_eq!(filing.accession_number, "0000320193-23-000106");
+        assert!(filing.is_xbrl);
+        assert!(!filing.is_inline_xbrl);
+    }
+
+    #[test]
+    fn test_report_date_parses_and_is_none_when_empty() {
+        assert_eq!(
+            filing_fixture(1, 1).report_date,
+            NaiveDate::from_ymd_opt(2023, 9, 30)
+        );
+
+        let filing: Filing = serde_json::from_str(
+            r#"{"accessionNumber": "0001104659-24-000001", "filingDate": "2024-01-02", "reportDate": "", "form": "8-K"}"#,
+        )
+        .unwrap();
+        assert_eq!(filing.report_date, None);
+
+        let recent: RecentFilings = serde_json::from_str(
+            r#"{
+                "accessionNumber": ["0000320193-23-000106", "0001104659-24-000001"],
+                "reportDate": ["2023-09-30", ""]
+            }"#,
+        )
+        .unwrap();
+        let filings = recent.to_filings().unwrap();
+        assert_eq!(filings[0].report_date, NaiveDate::from_ymd_opt(2023, 9, 30));
+        assert_eq!(filings[1].report_date, None);
+    }
+
+    #[test]
+    fn test_deserialize_company_metadata() {
+        let submissions: Submissions = serde_json::from_value(serde_json::json!({
+            "cik": "320193",
+            "name": "Apple Inc.",
+            "sic": "3571",
+            "sicDescription": "Electronic Computers",
+            "tickers": ["AAPL"],
+            "exchanges": ["Nasdaq", null]
+        }))
+        .unwrap();
+
+        assert_eq!(submissions.sic.as_deref(), Some("3571"));
+        assert_eq!(
+            submissions.sic_description.as_deref(),
+            Some("Electronic Computers")
+        );
+        assert_eq!(submissions.exchanges, vec!["Nasdaq".to_string()]);
+        assert!(submissions.filings.recent.is_empty());
+        assert!(submissions.filings.files.is_empty());
+
+        let sparse: Submissions = serde_json::from_value(serde_json::json!({
+            "cik": "1000045",
+            "name": "Nicholas Financial Inc",
+            "sic": "",
+            "sicDescription": null
+        }))
+        .unwrap();
+
+        assert_eq!(sparse.sic, None);
+        assert_eq!(sparse.sic_description, None);
+        assert!(sparse.exchanges.is_empty());
+    }
+
+    #[test]
+    fn test_addresses() {
+        let submissions: Submissions = serde_json::from_str(
+            r#"{
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "addresses": {
+                    "mailing": {"street1": "ONE APPLE PARK WAY", "street2": null, "city": "CUPERTINO", "stateOrCountry": "CA", "zipCode": "95014", "stateOrCountryDescription": "CA", "isForeignLocation": 0},
+                    "business": {"street1": "ONE APPLE PARK WAY", "street2": "BUILDING 1", "city": "CUPERTINO", "stateOrCountry": "CA", "zipCode": "95014", "stateOrCountryDescription": "CA", "isForeignLocation": 0}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mailing = submissions.mailing_address().unwrap();
+        assert_eq!(mailing.street1.as_deref(), Some("ONE APPLE PARK WAY"));
+        assert_eq!(mailing.street2, None);
+        assert_eq!(mailing.city.as_deref(), Some("CUPERTINO"));
+        assert_eq!(mailing.state_or_country.as_deref(), Some("CA"));
+        assert_eq!(mailing.zip_code.as_deref(), Some("95014"));
+
+        let business = submissions.business_address().unwrap();
+        assert_eq!(business.street2.as_deref(), Some("BUILDING 1"));
+
+        let bare: Submissions = serde_json::from_str(r#"{"cik": "1", "name": "X"}"#).unwrap();
+        assert!(bare.mailing_address().is_none());
+    }
+
+    #[test]
+    fn test_filings_since() {
+        let submissions: Submissions = serde_json::from_str(
+            r#"{
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["a1", "a2", "a3", "a4"],
+                        "filingDate": ["2024-05-03", "2024-05-02", "2024-05-02", "2024-05-01"],
+                        "acceptanceDateTime": ["2024-05-02T20:30:00.000Z", "", "2024-05-02T16:05:00.000Z", "2024-05-01T21:00:00.000Z"],
+                        "form": ["10-Q", "4", "8-K", "4"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let since = "2024-05-02T18:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let accessions: Vec<String> = submissions
+            .filings_since(since)
+            .into_iter()
+            .map(|f| f.accession_number)
+            .collect();
+        assert_eq!(accessions, vec!["a1", "a2"]);
+    }
+
+    #[test]
+    fn test_next_expected_quarterly() {
+        let submissions: Submissions = serde_json::from_str(
+            r#"{
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["a1", "a2", "a3", "a4", "a5", "a6", "a7"],
+                        "filingDate": ["2024-08-02", "2024-05-03", "2024-02-02", "2023-11-03", "2023-08-04", "2023-05-05", "2023-02-03"],
+                        "form": ["10-Q", "10-Q", "10-Q", "10-K", "10-Q", "10-Q", "10-Q"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        // The 10-K quarter is skipped: after August the next 10-Q follows the February one.
+        assert_eq!(
+            submissions.next_expected(FilingTypeOption::_10Q),
+            NaiveDate::from_ymd_opt(2025, 2, 2)
+        );
+        assert_eq!(submissions.next_expected(FilingTypeOption::_10K), None);
+    }
+
+    #[test]
+    fn test_next_expected_short_history_uses_median_gap() {
+        let submissions: Submissions = serde_json::from_str(
+            r#"{
+                "cik": "1",
+                "name": "NEW REGISTRANT INC",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["a1", "a2", "a3"],
+                        "filingDate": ["2024-08-14", "2024-05-15", "2024-02-14"],
+                        "form": ["10-Q", "10-Q", "10-Q"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let next = submissions.next_expected(FilingTypeOption::_10Q).unwrap();
+        assert_eq!(next, NaiveDate::from_ymd_opt(2024, 11, 13).unwrap());
+    }
+
+    #[test]
+    fn test_file_and_film_numbers() {
+        let recent: RecentFilings = serde_json::from_str(
+            r#"{
+                "accessionNumber": ["0000320193-23-000106", "0001214156-23-000004"],
+                "form": ["10-K", "4"],
+                "fileNumber": ["001-36743", ""],
+                "filmNumber": ["231373899", ""]
+            }"#,
+        )
+        .unwrap();
+        let filings = recent.to_filings().unwrap();
+
+        assert_eq!(filings[0].file_number.as_deref(), Some("001-36743"));
+        assert_eq!(filings[0].film_number.as_deref(), Some("231373899"));
+        assert_eq!(filings[1].file_number, None);
+        assert_eq!(filings[1].film_number, None);
+        assert_eq!(filing_fixture(1, 1).file_number, None);
+    }
+
+    #[test]
+    fn test_xbrl_kind_classification() {
+        assert_eq!(filing_fixture(1, 1).xbrl_kind(), XbrlKind::InlineXbrl);
+        assert_eq!(filing_fixture(0, 1).xbrl_kind(), XbrlKind::InlineXbrl);
+        assert_eq!(filing_fixture(1, 0).xbrl_kind(), XbrlKind::TraditionalXbrl);
+        assert_eq!(filing_fixture(0, 0).xbrl_kind(), XbrlKind::None);
+    }
+
+    #[test]
+    fn test_has_structured_data() {
+        assert!(filing_fixture(1, 1).has_structured_data());
+        assert!(filing_fixture(1, 0).has_structured_data());
+        assert!(!filing_fixture(0, 0).has_structured_data());
+
+        let cover_page_only = Filing {
+            form: "8-K".to_string(),
+            ..filing_fixture(1, 1)
+        };
+        assert!(!cover_page_only.has_structured_data());
+    }
+
+    #[test]
+    fn test_deserialize_insider_transaction_flags() {
+        let json = r#"
+            {
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "insiderTransactionForOwnerExists": 0,
+                "insiderTransactionForIssuerExists": 1
+            }
+        "#;
+        let submissions: Submissions = serde_json::from_str(json).unwrap();
+
+        assert!(!submissions.insider_transaction_for_owner_exists);
+        assert!(submissions.insider_transaction_for_issuer_exists);
+    }
+
+    #[test]
+    fn test_counts_by_year() {
+        let json = r#"
+            {
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["0000320193-24-000006", "0000320193-23-000106", "0000320193-23-000077", "0000320193-23-000064"],
+                        "filingDate": ["2024-02-02", "2023-11-03", "2023-08-04", "2023-05-05"],
+                        "form": ["10-Q", "10-K", "10-Q", "10-Q"]
+                    }
+                }
+            }
+        "#;
+        let submissions: Submissions = serde_json::from_str(json).unwrap();
+        let counts = submissions.counts_by_year();
+
+        assert_eq!(counts, BTreeMap::from([(2023, 3), (2024, 1)]));
+    }
+
+    #[test]
+    fn test_latest_filing_by_form() {
+        let json = r#"
+            {
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["0000320193-24-000006", "0000320193-23-000106", "0000320193-22-000108"],
+                        "filingDate": ["2024-02-02", "2023-11-03", "2022-10-28"],
+                        "form": ["10-Q", "10-K", "10-K"],
+                        "primaryDocument": ["aapl-20231230.htm", "aapl-20230930.htm", "aapl-20220924.htm"],
+                        "isXBRL": [1, 1, 1],
+                        "isInlineXBRL": [1, 1, 1]
+                    }
+                }
+            }
+        "#;
+        let submissions: Submissions = serde_json::from_str(json).unwrap();
+
+        let filing = submissions.latest_filing("10-K").unwrap();
+        assert_eq!(filing.accession_number, "0000320193-23-000106");
+        assert_eq!(filing.primary_document, "aapl-20230930.htm");
+        assert_eq!(filing.report_date, None);
+        assert!(filing.is_inline_xbrl);
+        assert!(submissions.latest_filing("8-K").is_none());
+    }
+
+    #[test]
+    fn test_to_filings_rejects_mismatched_columns() {
+        let recent: RecentFilings = serde_json::from_str(
+            r#"{
+                "accessionNumber": ["0000320193-24-000006", "0000320193-23-000106"],
+                "filingDate": ["2024-02-02", "2023-11-03"],
+                "form": ["10-Q"]
+            }"#,
+        )
+        .unwrap();
+
+        let result = recent.to_filings();
+        assert!(
+            matches!(result, Err(EDGARParserError::UnexpectedSchema(ref msg)) if msg.contains("`form`"))
+        );
+    }
+
+    #[test]
+    fn test_recent_filings_zips_columns_and_skips_unaligned_rows() {
+        let submissions: Submissions = serde_json::from_str(
+            r#"{
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["0000320193-24-000006", "0000320193-23-000106", "0000320193-23-000077"],
+                        "filingDate": ["2024-02-02", "2023-11-03", "2023-08-04"],
+                        "form": ["10-Q", "10-K", "10-Q"],
+                        "primaryDocument": ["aapl-20231230.htm", "aapl-20230930.htm"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let filings: Vec<Filing> = submissions.recent_filings().collect();
+
+        assert_eq!(filings.len(), 2);
+        assert_eq!(filings[1].accession_number, "0000320193-23-000106");
+        assert_eq!(filings[1].filing_date, "2023-11-03");
+        assert_eq!(filings[1].form, "10-K");
+        assert_eq!(filings[1].primary_document, "aapl-20230930.htm");
+    }
+
+    #[test]
+    fn test_to_filings_allows_absent_columns() {
+        let recent: RecentFilings = serde_json::from_str(
+            r#"{
+                "accessionNumber": ["0000320193-24-000006"],
+                "filingDate": ["2024-02-02"],
+                "form": ["10-Q"]
+            }"#,
+        )
+        .unwrap();
+
+        let filings = recent.to_filings().unwrap();
+        assert_eq!(filings.len(), 1);
+        assert_eq!(filings[0].report_date, None);
+    }
+
+    #[test]
+    fn test_by_form_groups_and_collects_unknown_forms() {
+        let submissions: Submissions = serde_json::from_str(
+            r#"{
+                "cik": "320193",
+                "name": "Apple Inc.",
+                "filings": {
+                    "recent": {
+                        "accessionNumber": ["a-4", "a-3", "a-2", "a-1"],
+                        "filingDate": ["2024-02-02", "2024-02-01", "2023-11-03", "2023-08-04"],
+                        "form": ["10-Q", "8-K", "NOT-A-FORM", "10-Q"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let groups = submissions.by_form();
+        let accessions = |form: FilingTypeOption| -> Vec<&str> {
+            groups[&FormType::Known(form)]
+                .iter()
+                .map(|f| f.accession_number.as_str())
+                .collect()
+        };
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(accessions(FilingTypeOption::_10Q), vec!["a-4", "a-1"]);
+        assert_eq!(accessions(FilingTypeOption::_8K), vec!["a-3"]);
+        let unknown = FormType::Unknown("NOT-A-FORM".to_string());
+        assert_eq!(groups[&unknown][0].accession_number, "a-2");
+        assert_eq!(groups.keys().last(), Some(&unknown));
+        assert_eq!(unknown.to_string(), "NOT-A-FORM");
+    }
+
+    #[test]
+    fn test_primary_document_url() {
+        let filing = filing_fixture(1, 1);
+        assert_eq!(
+            filing.primary_document_url(320193),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm"
+        );
+    }
+}
@@ -0,0 +1,730 @@
+use crate::config::EdgarConfig;
+use crate::edgar::{CompanyData, EdgarParser, parse_json};
+use crate::error::EDGARParserError;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+use tokio::sync::OnceCell;
+
+// Interval at which a process waiting on another's refresh re-checks the lock file.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Age after which a lock file is assumed to belong to a crashed process and is removed.
+const LOCK_STALE_AFTER: Duration = Duration::from_secs(60);
+
+// Ticker indexes downloaded by `TickerIndex::cached`, keyed by the ticker URL they came from.
+// Each URL has its own cell, so concurrent lookups of one URL wait for a single fetch while
+// lookups of other URLs proceed. The map lock itself is never held across a download.
+static TICKER_CACHE: Mutex<BTreeMap<String, Arc<OnceCell<Arc<TickerIndex>>>>> =
+    Mutex::new(BTreeMap::new());
+
+/// An index of the SEC company ticker file, keyed by ticker symbol. It can be persisted to
+/// disk so ticker lookups survive restarts without downloading the ticker file again.
+///
+/// # Fields
+/// - `companies`: Every company in the ticker file, keyed by ticker symbol.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TickerIndex {
+    pub companies: HashMap<String, CompanyData>,
+}
+
+impl TickerIndex {
+    /// Builds an index from the body of the SEC ticker file, whose entries are keyed by row number.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::JSONParseError` if the body is not a valid ticker file.
+    pub fn from_ticker_file(json: &str, config: &EdgarConfig) -> Result<Self, EDGARParserError> {
+        let rows: HashMap<String, CompanyData> = parse_json(json, config)?;
+
+        let companies = rows.into_values().map(|c| (c.ticker.clone(), c)).collect();
+
+        Ok(Self { companies })
+    }
+
+    /// Downloads the ticker file, trying each ticker URL in `config` in turn, and indexes it.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` if every request fails or
+    /// `EDGARParserError::JSONParseError` if the body cannot be parsed.
+    pub async fn fetch(config: &EdgarConfig) -> Result<Self, EDGARParserError> {
+        let json_body = EdgarParser::fetch_ticker_file(config).await?;
+        Self::from_ticker_file(&json_body, config)
+    }
+
+    /// Returns the index for the ticker URL in `config`. With `cache_ticker_file` on, the
+    /// index downloaded earlier in this process is reused, fetching it on first use; the
+    /// ticker file is about 1 MB, so lookups share one copy until `refresh_cached` replaces
+    /// it. With the option off, the default, this always fetches.
+    ///
+    /// # Errors
+    /// Returns the errors of `fetch` when the index is not cached yet. Failures are not cached.
+    pub async fn cached(config: &EdgarConfig) -> Result<Arc<Self>, EDGARParserError> {
+        if !config.cache_ticker_file {
+            return Ok(Arc::new(Self::fetch(config).await?));
+        }
+
+        let cell = {
+            let mut cache = TICKER_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+            Arc::clone(cache.entry(config.ticker_url.clone()).or_default())
+        };
+        let index = cell
+            .get_or_try_init(|| async { Self::fetch(config).await.map(Arc::new) })
+            .await?;
+        Ok(Arc::clone(index))
+    }
+
+    /// Downloads the ticker file again and replaces the copy `cached` returns for the ticker
+    /// URL in `config`. On failure the previous copy is kept.
+    ///
+    /// # Errors
+    /// Returns the errors of `fetch`.
+    pub async fn refresh_cached(config: &EdgarConfig) -> Result<Arc<Self>, EDGARParserError> {
+        let index = Arc::new(Self::fetch(config).await?);
+
+        let cell = OnceCell::new_with(Some(Arc::clone(&index)));
+        TICKER_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(config.ticker_url.clone(), Arc::new(cell));
+        Ok(index)
+    }
+
+    /// Looks up a company by its exact ticker symbol.
+    pub fn get(&self, ticker: &str) -> Option<&CompanyData> {
+        self.companies.get(ticker)
+    }
+
+    /// Returns every ticker record for `cik`, sorted by ticker; a CIK with several share
+    /// classes (e.g. `GOOGL` and `GOOG`) has one record per ticker. The records are looked up
+    /// in `companies` on each call, so edits to it are always reflected.
+    pub fn get_by_cik(&self, cik: u32) -> Vec<&CompanyData> {
+        let mut records: Vec<&CompanyData> = self
+            .companies
+            .values()
+            .filter(|c| c.cik_str == cik)
+            .collect();
+        records.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+        records
+    }
+
+    /// Finds the companies whose title or ticker contains `query`, ignoring case. Results are
+    /// ordered best match first: an exact ticker, then titles starting with the query, then
+    /// other title matches, then tickers merely containing it. Within each group shorter
+    /// titles come first. A blank query matches nothing.
+    pub fn search(&self, query: &str) -> Vec<CompanyData> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(u8, &CompanyData)> = self
+            .companies
+            .values()
+            .filter_map(|c| {
+                let ticker = c.ticker.to_lowercase();
+                let title = c.title.to_lowercase();
+                let rank = if ticker == query {
+                    0
+                } else if title.starts_with(&query) {
+                    1
+                } else if title.contains(&query) {
+                    2
+                } else if ticker.contains(&query) {
+                    3
+                } else {
+                    return None;
+                };
+                Some((rank, c))
+            })
+            .collect();
+        matches.sort_by(|(rank_a, a), (rank_b, b)| {
+            rank_a
+                .cmp(rank_b)
+                .then(a.title.len().cmp(&b.title.len()))
+                .then(a.ticker.cmp(&b.ticker))
+        });
+
+        matches.into_iter().map(|(_, c)| c.clone()).collect()
+    }
+
+    /// Compares the index with an earlier snapshot, `previous`. Records are matched by CIK:
+    /// a ticker listed in both with a new title is changed, and for the tickers of a CIK left
+    /// unmatched, the earlier and later ones are paired in ticker order as renames. Whatever
+    /// remains unpaired is added or removed.
+    pub fn diff(&self, previous: &TickerIndex) -> TickerDelta {
+        let mut by_cik: BTreeMap<u32, (Vec<&CompanyData>, Vec<&CompanyData>)> = BTreeMap::new();
+        for company in previous.companies.values() {
+            by_cik.entry(company.cik_str).or_default().0.push(company);
+        }
+        for company in self.companies.values() {
+            by_cik.entry(company.cik_str).or_default().1.push(company);
+        }
+
+        let mut delta = TickerDelta::default();
+        for (mut before, mut after) in by_cik.into_values() {
+            before.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+            after.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+
+            let mut renamed_from = Vec::new();
+            for old in before {
+                match after.iter().position(|new| new.ticker == old.ticker) {
+                    Some(i) => {
+                        let new = after.remove(i);
+                        if new != old {
+                            delta.changed.push(TickerChange {
+                                previous: old.clone(),
+                                current: new.clone(),
+                            });
+                        }
+                    }
+                    None => renamed_from.push(old),
+                }
+            }
+
+            let mut renamed_to = after.into_iter();
+            for old in renamed_from {
+                match renamed_to.next() {
+                    Some(new) => delta.changed.push(TickerChange {
+                        previous: old.clone(),
+                        current: new.clone(),
+                    }),
+                    None => delta.removed.push(old.clone()),
+                }
+            }
+            delta.added.extend(renamed_to.cloned());
+        }
+
+        delta
+    }
+
+    /// Writes the index to `path` as JSON. The file is written under a temporary name and
+    /// renamed into place, so readers never see a partially written index.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::IoError` if the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), EDGARParserError> {
+        let path = path.as_ref();
+        let json = serde_json::to_string(self)?;
+
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(format!(".{}.part", std::process::id()));
+        let partial = PathBuf::from(partial);
+
+        fs::write(&partial, json)
+            .and_then(|_| fs::rename(&partial, path))
+            .inspect_err(|_| {
+                let _ = fs::remove_file(&partial);
+            })?;
+        Ok(())
+    }
+
+    /// Reads an index previously written with `save`.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::IoError` if the file cannot be read or
+    /// `EDGARParserError::JSONParseError` if it is not a saved index.
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, EDGARParserError> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Loads the index from `path` if the file was modified within `max_age`; otherwise
+    /// fetches a fresh copy and saves it to `path` before returning it.
+    ///
+    /// # Errors
+    /// Returns the errors of `fetch` or `save` when the on-disk copy is missing or stale.
+    pub async fn load_or_fetch(
+        path: impl AsRef<Path>,
+        max_age: Duration,
+        config: &EdgarConfig,
+    ) -> Result<Self, EDGARParserError> {
+        let path = path.as_ref();
+
+        if Self::is_fresh(path, max_age) {
+            match Self::load_from_file(path) {
+                Ok(index) => return Ok(index),
+                Err(e) => log::warn!("Ignoring unreadable ticker cache {}: {}", path.display(), e),
+            }
+        }
+
+        let index = Self::fetch(config).await?;
+        index.save(path)?;
+        Ok(index)
+    }
+
+    /// Behaves like `load_or_fetch`, but coordinates refreshes between processes sharing the
+    /// same cache file. A `<path>.lock` file ensures only one process downloads the ticker file
+    /// at a time; the others use the stale copy when there is one, or wait for the refresh to
+    /// finish and load its result.
+    ///
+    /// # Errors
+    /// Returns the errors of `fetch` or `save` when this process performs the refresh, or
+    /// `EDGARParserError::IoError` if the lock file cannot be created.
+    pub async fn load_or_fetch_locked(
+        path: impl AsRef<Path>,
+        max_age: Duration,
+        config: &EdgarConfig,
+    ) -> Result<Self, EDGARParserError> {
+        let path = path.as_ref();
+
+        loop {
+            if Self::is_fresh(path, max_age)
+                && let Ok(index) = Self::load_from_file(path)
+            {
+                return Ok(index);
+            }
+
+            if let Some(_lock) = RefreshLock::acquire(path)? {
+                let index = Self::fetch(config).await?;
+                index.save(path)?;
+                return Ok(index);
+            }
+
+            // Another process is refreshing; a stale copy is better than waiting on it.
+            if let Ok(index) = Self::load_from_file(path) {
+                return Ok(index);
+            }
+
+            tokio::time::sleep(LOCK_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Returns `true` if the file at `path` exists and was modified within `max_age`.
+    fn is_fresh(path: &Path, max_age: Duration) -> bool {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age < max_age)
+    }
+}
+
+/// The differences between two snapshots of the ticker file, for keeping a local copy of
+/// the CIK database in sync without reloading it. Entries are sorted by CIK and ticker.
+///
+/// # Fields
+/// - `added`: Records for tickers that are new in the later snapshot.
+/// - `removed`: Records for tickers that are no longer listed.
+/// - `changed`: Records whose ticker or title changed, matched by CIK.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TickerDelta {
+    pub added: Vec<CompanyData>,
+    pub removed: Vec<CompanyData>,
+    pub changed: Vec<TickerChange>,
+}
+
+/// A ticker record that changed between two snapshots of the ticker file, e.g. a new ticker
+/// symbol after a rebrand or a corrected company name.
+///
+/// # Fields
+/// - `previous`: The record in the earlier snapshot.
+/// - `current`: The record in the later snapshot.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TickerChange {
+    pub previous: CompanyData,
+    pub current: CompanyData,
+}
+
+impl TickerDelta {
+    /// Returns `true` if the snapshots hold the same records.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A single ticker record of a `CompactTickerIndex`.
+///
+/// # Fields
+/// - `cik`: The company's CIK.
+/// - `ticker`: The ticker symbol.
+/// - `title`: The company name, shared with every other ticker of the same company.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompactCompany {
+    pub cik: u32,
+    pub ticker: Box<str>,
+    pub title: Arc<str>,
+}
+
+/// A read-only ticker index with a smaller resident footprint than `TickerIndex`, for
+/// services that keep the whole ticker file in memory. Each record is stored once, with
+/// tickers as `Box<str>` and titles interned as `Arc<str>`, and both lookups are binary
+/// searches over sorted slices instead of hash maps of owned copies.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CompactTickerIndex {
+    // Every record, sorted by ticker.
+    companies: Box<[CompactCompany]>,
+
+    // Positions in `companies`, sorted by CIK and then ticker.
+    by_cik: Box<[u32]>,
+}
+
+impl CompactTickerIndex {
+    /// Builds a compact index from the body of the SEC ticker file.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::JSONParseError` if the body is not a valid ticker file.
+    pub fn from_ticker_file(json: &str, config: &EdgarConfig) -> Result<Self, EDGARParserError> {
+        let rows: HashMap<String, CompanyData> = parse_json(json, config)?;
+        Ok(Self::from_records(rows.into_values()))
+    }
+
+    /// Builds a compact index from ticker records, interning titles shared between tickers.
+    fn from_records(records: impl IntoIterator<Item = CompanyData>) -> Self {
+        let mut titles: HashMap<String, Arc<str>> = HashMap::new();
+        let mut companies: Vec<CompactCompany> = records
+            .into_iter()
+            .map(|c| {
+                let title = titles
+                    .entry(c.title)
+                    .or_insert_with_key(|title| Arc::from(title.as_str()))
+                    .clone();
+                CompactCompany {
+                    cik: c.cik_str,
+                    ticker: c.ticker.into_boxed_str(),
+                    title,
+                }
+            })
+            .collect();
+        companies.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+
+        let mut by_cik: Vec<u32> = (0..companies.len() as u32).collect();
+        by_cik.sort_by_key(|&i| companies[i as usize].cik);
+
+        Self {
+            companies: companies.into_boxed_slice(),
+            by_cik: by_cik.into_boxed_slice(),
+        }
+    }
+
+    /// Downloads the ticker file, trying each ticker URL in `config` in turn, and indexes it.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::HttpError` if every request fails or
+    /// `EDGARParserError::JSONParseError` if the body cannot be parsed.
+    pub async fn fetch(config: &EdgarConfig) -> Result<Self, EDGARParserError> {
+        let json_body = EdgarParser::fetch_ticker_file(config).await?;
+        Self::from_ticker_file(&json_body, config)
+    }
+
+    /// Returns the number of ticker records in the index.
+    pub fn len(&self) -> usize {
+        self.companies.len()
+    }
+
+    /// Returns `true` if the index holds no tickers.
+    pub fn is_empty(&self) -> bool {
+        self.companies.is_empty()
+    }
+
+    /// Looks up a company by its exact ticker symbol.
+    pub fn get(&self, ticker: &str) -> Option<&CompactCompany> {
+        self.companies
+            .binary_search_by(|c| (*c.ticker).cmp(ticker))
+            .ok()
+            .map(|i| &self.companies[i])
+    }
+
+    /// Returns every ticker record for `cik`, sorted by ticker.
+    pub fn get_by_cik(&self, cik: u32) -> impl Iterator<Item = &CompactCompany> {
+        let company = |i: &u32| &self.companies[*i as usize];
+        let start = self.by_cik.partition_point(|i| company(i).cik < cik);
+        let end = self.by_cik.partition_point(|i| company(i).cik <= cik);
+        self.by_cik[start..end].iter().map(company)
+    }
+}
+
+impl From<&TickerIndex> for CompactTickerIndex {
+    fn from(index: &TickerIndex) -> Self {
+        Self::from_records(index.companies.values().cloned())
+    }
+}
+
+/// An exclusive lock on refreshing a ticker cache, held as a `<path>.lock` file that is
+/// removed when the guard is dropped.
+struct RefreshLock {
+    path: PathBuf,
+}
+
+impl RefreshLock {
+    /// Creates the lock file for the cache at `cache_path`, returning `None` if another
+    /// process already holds it. Lock files older than `LOCK_STALE_AFTER` are reclaimed.
+    fn acquire(cache_path: &Path) -> Result<Option<Self>, EDGARParserError> {
+        let mut lock_path = cache_path.as_os_str().to_owned();
+        lock_path.push(".lock");
+        let path = PathBuf::from(lock_path);
+
+        if Self::is_stale(&path) {
+            let _ = fs::remove_file(&path);
+        }
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => Ok(Some(Self { path })),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl RefreshLock {
+    /// Returns `true` if a lock file exists at `path` and was last modified more than
+    /// `LOCK_STALE_AFTER` ago. A missing or unreadable lock file is not stale, so a lock
+    /// another process has just created is never removed.
+    fn is_stale(path: &Path) -> bool {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age > LOCK_STALE_AFTER)
+    }
+}
+
+impl Drop for RefreshLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TICKER_FILE_FIXTURE: &str = r#"{
+        "0": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+        "1": {"cik_str": 789019, "ticker": "MSFT", "title": "MICROSOFT CORP"}
+    }"#;
+
+    #[test]
+    fn test_duplicate_cik_keeps_every_ticker() {
+        let json = r#"{
+            "0": {"cik_str": 1652044, "ticker": "GOOGL", "title": "Alphabet Inc."},
+            "1": {"cik_str": 1652044, "ticker": "GOOG", "title": "Alphabet Inc."},
+            "2": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."}
+        }"#;
+        let index = TickerIndex::from_ticker_file(json, &EdgarConfig::for_tests()).unwrap();
+
+        let tickers: Vec<&str> = index
+            .get_by_cik(1652044)
+            .iter()
+            .map(|c| c.ticker.as_str())
+            .collect();
+        assert_eq!(tickers, vec!["GOOG", "GOOGL"]);
+        assert_eq!(index.get_by_cik(320193).len(), 1);
+        assert!(index.get_by_cik(789019).is_empty());
+
+        let mut index = index;
+        index.companies.remove("GOOG");
+        assert_eq!(index.get_by_cik(1652044).len(), 1);
+    }
+
+    #[test]
+    fn test_search_ranks_matches() {
+        let json = r#"{
+            "0": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+            "1": {"cik_str": 1418091, "ticker": "APLE", "title": "Apple Hospitality REIT, Inc."},
+            "2": {"cik_str": 1999001, "ticker": "PINE", "title": "Pineapple Holdings"},
+            "3": {"cik_str": 789019, "ticker": "MSFT", "title": "MICROSOFT CORP"}
+        }"#;
+        let index = TickerIndex::from_ticker_file(json, &EdgarConfig::for_tests()).unwrap();
+
+        let tickers: Vec<String> = index
+            .search("apple")
+            .into_iter()
+            .map(|c| c.ticker)
+            .collect();
+        assert_eq!(tickers, vec!["AAPL", "APLE", "PINE"]);
+
+        let by_ticker = index.search("msft");
+        assert_eq!(by_ticker.len(), 1);
+        assert_eq!(by_ticker[0].title, "MICROSOFT CORP");
+        assert!(index.search("  ").is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_added_and_renamed_tickers() {
+        let previous = r#"{
+            "0": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+            "1": {"cik_str": 1326801, "ticker": "FB", "title": "Facebook Inc"},
+            "2": {"cik_str": 1090872, "ticker": "A", "title": "AGILENT TECHNOLOGIES, INC."}
+        }"#;
+        let current = r#"{
+            "0": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+            "1": {"cik_str": 1326801, "ticker": "META", "title": "Meta Platforms, Inc."},
+            "2": {"cik_str": 1045810, "ticker": "NVDA", "title": "NVIDIA CORP"}
+        }"#;
+        let previous = TickerIndex::from_ticker_file(previous, &EdgarConfig::for_tests()).unwrap();
+        let current = TickerIndex::from_ticker_file(current, &EdgarConfig::for_tests()).unwrap();
+
+        let delta = current.diff(&previous);
+
+        let added: Vec<&str> = delta.added.iter().map(|c| c.ticker.as_str()).collect();
+        assert_eq!(added, vec!["NVDA"]);
+        let removed: Vec<&str> = delta.removed.iter().map(|c| c.ticker.as_str()).collect();
+        assert_eq!(removed, vec!["A"]);
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.changed[0].previous.ticker, "FB");
+        assert_eq!(delta.changed[0].current.ticker, "META");
+        assert_eq!(delta.changed[0].current.title, "Meta Platforms, Inc.");
+
+        assert!(current.diff(&current).is_empty());
+    }
+
+    #[test]
+    fn test_compact_index_resolves_and_interns_titles() {
+        let json = r#"{
+            "0": {"cik_str": 1652044, "ticker": "GOOGL", "title": "Alphabet Inc."},
+            "1": {"cik_str": 1652044, "ticker": "GOOG", "title": "Alphabet Inc."},
+            "2": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+            "3": {"cik_str": 789019, "ticker": "MSFT", "title": "MICROSOFT CORP"}
+        }"#;
+        let index = CompactTickerIndex::from_ticker_file(json, &EdgarConfig::for_tests()).unwrap();
+
+        assert_eq!(index.len(), 4);
+        let msft = index.get("MSFT").unwrap();
+        assert_eq!(msft.cik, 789019);
+        assert_eq!(&*msft.title, "MICROSOFT CORP");
+        assert!(index.get("MSF").is_none());
+
+        let alphabet: Vec<&CompactCompany> = index.get_by_cik(1652044).collect();
+        let tickers: Vec<&str> = alphabet.iter().map(|c| &*c.ticker).collect();
+        assert_eq!(tickers, vec!["GOOG", "GOOGL"]);
+        assert!(Arc::ptr_eq(&alphabet[0].title, &alphabet[1].title));
+        assert_eq!(index.get_by_cik(1).count(), 0);
+
+        let full = TickerIndex::from_ticker_file(json, &EdgarConfig::for_tests()).unwrap();
+        assert_eq!(CompactTickerIndex::from(&full), index);
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("agora_{}_{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_from_ticker_file() {
+        let index =
+            TickerIndex::from_ticker_file(TICKER_FILE_FIXTURE, &EdgarConfig::for_tests()).unwrap();
+
+        assert_eq!(index.companies.len(), 2);
+        assert_eq!(index.get("MSFT").unwrap().cik_str, 789019);
+        assert!(index.get("GOOG").is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_resolves_without_network() {
+        let path = temp_path("ticker_index_roundtrip");
+        let index =
+            TickerIndex::from_ticker_file(TICKER_FILE_FIXTURE, &EdgarConfig::for_tests()).unwrap();
+        index.save(&path).unwrap();
+
+        let loaded = TickerIndex::load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, index);
+        let parser =
+            EdgarParser::create_from_ticker_index("AAPL", &loaded, &EdgarConfig::for_tests())
+                .unwrap();
+        assert_eq!(parser.cik_str, Some(320193));
+        assert_eq!(parser.leading_zero_cik, "0000320193");
+    }
+
+    #[tokio::test]
+    async fn test_load_or_fetch_uses_fresh_file() {
+        let path = temp_path("ticker_index_fresh");
+        let index =
+            TickerIndex::from_ticker_file(TICKER_FILE_FIXTURE, &EdgarConfig::for_tests()).unwrap();
+        index.save(&path).unwrap();
+
+        // An unreachable ticker URL proves the fresh on-disk copy is used.
+        let config = EdgarConfig {
+            ticker_url: "http://127.0.0.1:1/company_tickers.json".to_string(),
+            ..Default::default()
+        };
+        let loaded = TickerIndex::load_or_fetch(&path, Duration::from_secs(3600), &config)
+            .await
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, index);
+    }
+
+    #[tokio::test]
+    async fn test_load_or_fetch_refreshes_stale_file() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker_mock = server
+            .mock("GET", "/company_tickers.json")
+            .with_status(200)
+            .with_body(TICKER_FILE_FIXTURE)
+            .create_async()
+            .await;
+
+        let path = temp_path("ticker_index_stale");
+        TickerIndex::default().save(&path).unwrap();
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/company_tickers.json", server.url()),
+            ..EdgarConfig::for_tests()
+        };
+        let loaded = TickerIndex::load_or_fetch(&path, Duration::ZERO, &config)
+            .await
+            .unwrap();
+        let saved = TickerIndex::load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        ticker_mock.assert_async().await;
+        assert_eq!(loaded.companies.len(), 2);
+        assert_eq!(saved, loaded);
+    }
+
+    #[test]
+    fn test_refresh_lock_only_reclaims_stale_locks() {
+        let cache = temp_path("ticker_index_lock");
+        let held = RefreshLock::acquire(&cache).unwrap().unwrap();
+
+        // A lock held by another process is left alone while it is fresh.
+        assert!(RefreshLock::acquire(&cache).unwrap().is_none());
+
+        let stale = SystemTime::now() - LOCK_STALE_AFTER - Duration::from_secs(1);
+        fs::File::options()
+            .write(true)
+            .open(&held.path)
+            .and_then(|f| f.set_modified(stale))
+            .unwrap();
+        let reclaimed = RefreshLock::acquire(&cache).unwrap();
+        assert!(reclaimed.is_some());
+
+        std::mem::forget(held);
+        drop(reclaimed);
+        assert!(!Path::new(&format!("{}.lock", cache.display())).exists());
+    }
+
+    #[tokio::test]
+    async fn test_load_or_fetch_locked_fetches_once() {
+        let mut server = mockito::Server::new_async().await;
+        let ticker_mock = server
+            .mock("GET", "/company_tickers.json")
+            .with_status(200)
+            .with_body(TICKER_FILE_FIXTURE)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let path = temp_path("ticker_index_locked");
+        let _ = fs::remove_file(&path);
+
+        let config = EdgarConfig {
+            ticker_url: format!("{}/company_tickers.json", server.url()),
+            ..EdgarConfig::for_tests()
+        };
+        let max_age = Duration::from_secs(3600);
+        let (first, second) = tokio::join!(
+            TickerIndex::load_or_fetch_locked(&path, max_age, &config),
+            TickerIndex::load_or_fetch_locked(&path, max_age, &config),
+        );
+        fs::remove_file(&path).unwrap();
+
+        ticker_mock.assert_async().await;
+        assert_eq!(first.unwrap(), second.unwrap());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::error::EDGARParserError;
+use std::fmt;
+
+/// Placeholder User-Agent that older versions of this crate sent when none was configured.
+/// It identifies nobody, so it is rejected like any other non-descriptive agent.
+const PLACEHOLDER_USER_AGENT: &str = "MyRustApp support@myrustapp.com";
+
+/// A User-Agent header value in the `Company Name email@example.com` format the SEC requires
+/// of automated clients. Requests with a missing or malformed User-Agent are answered with 403.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct UserAgent(String);
+
+impl UserAgent {
+    /// Builds a User-Agent from a company (or individual) name and a contact email.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::InvalidUserAgent` if the company is blank or the email
+    /// does not look like an address, e.g. `admin@adamsinc.com`.
+    pub fn new(company: &str, email: &str) -> Result<Self, EDGARParserError> {
+        let company = company.trim();
+        let email = email.trim();
+
+        if company.is_empty() {
+            return Err(EDGARParserError::InvalidUserAgent(
+                "company name must not be empty".to_string(),
+            ));
+        }
+        if !is_email_like(email) {
+            return Err(EDGARParserError::InvalidUserAgent(format!(
+                "contact email {:?} is not a valid address",
+                email
+            )));
+        }
+
+        Ok(Self(format!("{} {}", company, email)))
+    }
+
+    /// Validates a complete User-Agent string such as `Adams Incorporated admin@adamsinc.com`,
+    /// collapsing runs of whitespace into single spaces.
+    ///
+    /// # Errors
+    /// Returns `EDGARParserError::InvalidUserAgent` if the string is blank, has no word that
+    /// looks like a contact email, or is the crate's old placeholder agent.
+    pub fn parse(raw: &str) -> Result<Self, EDGARParserError> {
+        let normalized = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+        let raw = normalized.as_str();
+
+        if raw.is_empty() {
+            return Err(EDGARParserError::InvalidUserAgent(
+                "User-Agent must not be empty".to_string(),
+            ));
+        }
+        if !raw.split(' ').any(is_email_like) {
+            return Err(EDGARParserError::InvalidUserAgent(format!(
+                "{:?} must include a contact email",
+                raw
+            )));
+        }
+        if raw.eq_ignore_ascii_case(PLACEHOLDER_USER_AGENT) {
+            return Err(EDGARParserError::InvalidUserAgent(format!(
+                "{:?} is a placeholder; identify your organization and contact email",
+                raw
+            )));
+        }
+
+        Ok(Self(raw.to_string()))
+    }
+
+    /// Returns the header value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Returns `true` if `email` has the `local@domain.tld` shape of an address. This is a sanity
+/// check against typos and placeholders, not full RFC 5322 validation.
+fn is_email_like(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+impl fmt::Display for UserAgent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<UserAgent> for String {
+    fn from(user_agent: UserAgent) -> Self {
+        user_agent.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_valid() {
+        let ua = UserAgent::new("Adams Incorporated", "admin@adamsinc.com").unwrap();
+        assert_eq!(ua.as_str(), "Adams Incorporated admin@adamsinc.com");
+        assert_eq!(String::from(ua), "Adams Incorporated admin@adamsinc.com");
+    }
+
+    #[test]
+    fn test_new_email_without_at() {
+        let result = UserAgent::new("Adams Incorporated", "admin.adamsinc.com");
+        assert!(matches!(result, Err(EDGARParserError::InvalidUserAgent(_))));
+    }
+
+    #[test]
+    fn test_parse_normalizes_and_requires_email() {
+        let ua = UserAgent::parse("  Adams   Incorporated\tadmin@adamsinc.com ").unwrap();
+        assert_eq!(ua.as_str(), "Adams Incorporated admin@adamsinc.com");
+
+        for invalid in [
+            "Adams Incorporated admin@",
+            "Adams Incorporated @adamsinc.com",
+            "Adams Incorporated admin@adamsinc",
+            "Adams Incorporated admin@@adamsinc.com",
+            "Adams Incorporated admin@adamsinc.com.",
+        ] {
+            assert!(
+                matches!(
+                    UserAgent::parse(invalid),
+                    Err(EDGARParserError::InvalidUserAgent(_))
+                ),
+                "{} should be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_empty_company() {
+        let result = UserAgent::new("  ", "admin@adamsinc.com");
+        assert!(matches!(result, Err(EDGARParserError::InvalidUserAgent(_))));
+    }
+}
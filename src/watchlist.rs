@@ -0,0 +1,111 @@
+use crate::ticker_index::TickerIndex;
+use std::collections::HashMap;
+
+// Largest edit distance between an unknown ticker and a listed one for it to be suggested.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+// Number of company titles suggested for each unknown ticker.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The outcome of checking a list of tickers against the SEC ticker file.
+///
+/// # Fields
+/// - `resolved`: Tickers found in the ticker file with their CIK, in input order. Tickers are
+///   upper-cased, as the SEC lists them.
+/// - `unknown`: Tickers not in the ticker file, in input order.
+/// - `suggestions`: For each unknown ticker, the titles of companies whose ticker is closest
+///   to it (within two edits), nearest first. Unknown tickers with no close match are absent.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct WatchlistReport {
+    pub resolved: Vec<(String, u32)>,
+    pub unknown: Vec<String>,
+    pub suggestions: HashMap<String, Vec<String>>,
+}
+
+impl WatchlistReport {
+    /// Checks `tickers` against an already loaded `TickerIndex`.
+    pub fn from_index(tickers: &[&str], index: &TickerIndex) -> Self {
+        let mut report = Self::default();
+
+        for ticker in tickers {
+            let ticker = ticker.trim().to_ascii_uppercase();
+            match index.get(&ticker) {
+                Some(company) => report.resolved.push((ticker, company.cik_str)),
+                None => {
+                    let suggestions = closest_titles(&ticker, index);
+                    if !suggestions.is_empty() {
+                        report.suggestions.insert(ticker.clone(), suggestions);
+                    }
+                    report.unknown.push(ticker);
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Returns `true` if every ticker resolved.
+    pub fn is_clean(&self) -> bool {
+        self.unknown.is_empty()
+    }
+}
+
+/// Returns the titles of the companies whose tickers are nearest to `ticker`.
+fn closest_titles(ticker: &str, index: &TickerIndex) -> Vec<String> {
+    let mut candidates: Vec<(usize, &str, &str)> = index
+        .companies
+        .values()
+        .map(|c| {
+            (
+                levenshtein(ticker, &c.ticker),
+                c.ticker.as_str(),
+                c.title.as_str(),
+            )
+        })
+        .filter(|(distance, _, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    candidates.sort();
+
+    let mut titles: Vec<String> = Vec::new();
+    for (_, _, title) in candidates {
+        if titles.len() == MAX_SUGGESTIONS {
+            break;
+        }
+        if !titles.iter().any(|t| t == title) {
+            titles.push(title.to_string());
+        }
+    }
+    titles
+}
+
+/// Computes the Levenshtein edit distance between two ASCII strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.bytes().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("AAPL", "AAPL"), 0);
+        assert_eq!(levenshtein("APPL", "AAPL"), 1);
+        assert_eq!(levenshtein("TSLA", "TLSA"), 2);
+        assert_eq!(levenshtein("MSF", "MSFT"), 1);
+        assert_eq!(levenshtein("", "GOOG"), 4);
+    }
+}